@@ -20,6 +20,12 @@
 pub trait Region : Default {
     /// The type of item the region contains.
     type Item;
+    /// Whether copies may share region memory, for example because equal items are interned.
+    ///
+    /// The memory of such a region is not the sum of `item_heap_size` over its items, and
+    /// discarding an item need not make any of it reclaimable. Composite regions share
+    /// memory if any of their constituent regions do.
+    const SHARES_MEMORY: bool = false;
     /// Add a new element to the region.
     ///
     /// The argument will be copied in to the region and returned as an
@@ -38,6 +44,71 @@ pub trait Region : Default {
     /// should only be called after all instances returned by `copy` have
     /// been disposed of, as this method may invalidate their contents.
     fn clear(&mut self);
+    /// Discards the contents like `clear`, but retains allocations for reuse.
+    ///
+    /// The default implementation calls `clear`, which suits regions that retain their
+    /// allocations anyway. Regions with stashes should retain the stashed allocations too.
+    #[inline]
+    fn clear_retaining(&mut self) {
+        self.clear()
+    }
+
+    /// The number of allocations the region and its constituent regions have filled and stashed.
+    ///
+    /// The default implementation reports none, which is correct for regions without stashes.
+    #[inline]
+    fn stashed_chunks(&self) -> usize {
+        0
+    }
+    /// Installs `hook` in the region and its constituent regions, to observe their allocations.
+    ///
+    /// Passing `None` removes any installed hook. The default implementation ignores the hook,
+    /// which is correct for regions that allocate nothing of their own.
+    #[cfg(feature = "hooks")]
+    #[inline]
+    fn set_hook(&mut self, hook: Option<&RegionHook>) {
+        let _ = hook;
+    }
+    /// Records the current position of the region, to which it can later be rolled back.
+    ///
+    /// The position is appended to `marks` as a sequence of integers, which composite
+    /// regions form by recording the positions of their constituent regions in order.
+    /// The default implementation records nothing, and supports no rollback.
+    #[inline]
+    fn checkpoint(&self, marks: &mut Vec<usize>) {
+        let _ = marks;
+    }
+    /// Discards items copied since a position recorded by `checkpoint`.
+    ///
+    /// The implementation should consume from `marks` exactly what `checkpoint` appended,
+    /// in the same order. The default implementation consumes nothing and retains all items,
+    /// which is correct but reclaims no memory.
+    ///
+    /// # Safety
+    ///
+    /// The marks must have been recorded by this region, and the region must not have been
+    /// cleared or rolled back to an earlier position in the meantime. All instances returned by
+    /// `copy` since the checkpoint must have been disposed of, as this method may invalidate them.
+    #[inline]
+    unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+        let _ = marks;
+    }
+
+    /// The bytes of region memory that copying `item` in to the region uses.
+    ///
+    /// This is the memory that `reserve_items` reserves for the item, and does not depend on
+    /// the state of the region; regions that may share memory between items report an upper bound
+    /// and set [SHARES_MEMORY](Self::SHARES_MEMORY).
+    /// The default implementation measures the item by copying it in to an empty region.
+    fn item_heap_size(item: &Self::Item) -> usize {
+        let mut region = Self::default();
+        // Unsafety justified in that the copy does not own its resources.
+        std::mem::forget(unsafe { region.copy(item) });
+        let mut size = 0;
+        region.heap_size(|len, _cap| size += len);
+        region.clear();
+        size
+    }
 
     /// Ensure that the region can absorb `items` without reallocation.
     fn reserve_items<'a, I>(&mut self, items: I)
@@ -83,6 +154,117 @@ pub trait Region : Default {
     /// The closure is free to sum the parameters, or do more advanced analysis such as creating a
     /// histogram of allocation sizes.
     fn heap_size(&self, callback: impl FnMut(usize, usize));
+
+    /// Determine this region's memory used and reserved capacity in bytes, by path.
+    ///
+    /// As [heap_size](Self::heap_size), but the `callback` also receives the path of each
+    /// allocation, formed by appending segments to `path`: for example `.0` for the first
+    /// component of a tuple, or `.vec.elements` for the elements of vectors. The default
+    /// implementation reports all allocations at `path`.
+    fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+        let path = &*path;
+        self.heap_size(|used, capacity| callback(path, used, capacity));
+    }
+}
+
+/// A [Region] whose capacity can be measured as a reusable [CapacityPlan].
+pub trait RegionCapacity: Region {
+    /// A plan of the capacity of the region, which can be measured and reserved.
+    type Capacity: CapacityPlan;
+    /// The capacity that copying `item` in to the region uses.
+    fn measure_item(item: &Self::Item) -> Self::Capacity;
+    /// The capacity that the items in the region use.
+    fn measure(&self) -> Self::Capacity;
+    /// Ensure that the region can absorb items of total capacity `capacity` without reallocation.
+    fn reserve_capacity(&mut self, capacity: &Self::Capacity);
+    /// The capacity that copying `items` in to the region uses.
+    fn measure_items<'a, I>(items: I) -> Self::Capacity
+    where
+        Self: 'a,
+        I: Iterator<Item=&'a Self::Item>
+    {
+        let mut capacity = Self::Capacity::default();
+        for item in items {
+            capacity.add(&Self::measure_item(item));
+        }
+        capacity
+    }
+    /// The capacity that the items of `regions` use.
+    fn measure_regions<'a, I>(regions: I) -> Self::Capacity
+    where
+        Self: 'a,
+        I: Iterator<Item = &'a Self>
+    {
+        let mut capacity = Self::Capacity::default();
+        for region in regions {
+            capacity.add(&region.measure());
+        }
+        capacity
+    }
+}
+
+/// A marker for regions whose `copy` is a bitwise copy of the item, and which hold no allocations.
+///
+/// Containers can rely on this to hand out mutable references to items.
+///
+/// # Safety
+///
+/// Implementors must ensure that items own no resources and reference no region memory,
+/// so that they may be modified, dropped, or outlive the region.
+pub unsafe trait TrivialRegion: Region { }
+
+/// Appends `segment` to `path` for the duration of `logic`.
+pub(crate) fn with_segment(path: &mut String, segment: impl std::fmt::Display, logic: impl FnOnce(&mut String)) {
+    use std::fmt::Write;
+    let len = path.len();
+    write!(path, "{}", segment).unwrap();
+    logic(path);
+    path.truncate(len);
+}
+
+/// A plan of the capacity of a region, which can be combined with other plans and scaled.
+///
+/// Plans are measured from items with [RegionCapacity::measure_items] or from regions with
+/// [RegionCapacity::measure_regions], and applied with [RegionCapacity::reserve_capacity]. They can be
+/// stored and reused, unlike the iterators that `reserve_items` consumes.
+pub trait CapacityPlan: Default + Clone + std::fmt::Debug {
+    /// Adds the capacity of `other` to `self`.
+    fn add(&mut self, other: &Self);
+    /// Scales the capacity of `self` by `factor`, rounding up.
+    fn scale(&mut self, factor: f64);
+}
+
+impl CapacityPlan for () {
+    #[inline(always)]
+    fn add(&mut self, _other: &Self) { }
+    #[inline(always)]
+    fn scale(&mut self, _factor: f64) { }
+}
+
+// A number of items, or elements of an allocation.
+impl CapacityPlan for usize {
+    #[inline(always)]
+    fn add(&mut self, other: &Self) {
+        *self += other;
+    }
+    #[inline(always)]
+    fn scale(&mut self, factor: f64) {
+        *self = (*self as f64 * factor).ceil() as usize;
+    }
+}
+
+// A region with two constituents, for example a sequence of elements and their inner region.
+impl<A: CapacityPlan, B: CapacityPlan> CapacityPlan for (A, B) {
+    #[inline(always)]
+    fn add(&mut self, other: &Self) {
+        self.0.add(&other.0);
+        self.1.add(&other.1);
+    }
+    #[inline(always)]
+    fn scale(&mut self, factor: f64) {
+        self.0.scale(factor);
+        self.1.scale(factor);
+    }
 }
 
 /// A vacuous region that just copies items.
@@ -106,6 +288,10 @@ impl<T: Copy> Region for CopyRegion<T> {
     #[inline(always)]
     fn clear(&mut self) { }
 
+    #[inline(always)]
+    fn item_heap_size(_item: &Self::Item) -> usize {
+        0
+    }
     fn reserve_items<'a, I>(&mut self, _items: I)
     where
         Self: 'a,
@@ -122,6 +308,399 @@ impl<T: Copy> Region for CopyRegion<T> {
     }
 }
 
+impl<T: Copy> RegionCapacity for CopyRegion<T> {
+    type Capacity = ();
+    #[inline(always)]
+    fn measure_item(_item: &Self::Item) -> Self::Capacity { }
+    #[inline(always)]
+    fn measure(&self) -> Self::Capacity { }
+    #[inline(always)]
+    fn reserve_capacity(&mut self, _capacity: &Self::Capacity) { }
+}
+
+unsafe impl<T: Copy> TrivialRegion for CopyRegion<T> { }
+
+/// A marker for types whose bitwise aliases may be used alongside the value they alias.
+///
+/// [CloneRegion] returns a bitwise alias of each clone it retains, and so both share the
+/// resources the clone owns. This is sound only if shared references to the alias cannot
+/// change what the clone owns.
+///
+/// # Safety
+///
+/// Implementors must not contain interior mutability (for example `Cell`, `RefCell`, or
+/// `Mutex`) outside of the allocations they own, as mutating it through the alias would
+/// leave the clone referencing resources that have been released.
+pub unsafe trait AliasSafe { }
+
+unsafe impl AliasSafe for String { }
+unsafe impl<T: ?Sized> AliasSafe for Box<T> { }
+unsafe impl<T: ?Sized> AliasSafe for std::rc::Rc<T> { }
+unsafe impl<T: ?Sized> AliasSafe for std::sync::Arc<T> { }
+unsafe impl<T> AliasSafe for Vec<T> { }
+unsafe impl<T> AliasSafe for std::collections::VecDeque<T> { }
+unsafe impl<T> AliasSafe for std::collections::LinkedList<T> { }
+unsafe impl<T> AliasSafe for std::collections::BinaryHeap<T> { }
+unsafe impl<K, V> AliasSafe for std::collections::BTreeMap<K, V> { }
+unsafe impl<T> AliasSafe for std::collections::BTreeSet<T> { }
+unsafe impl<K, V, S: AliasSafe> AliasSafe for std::collections::HashMap<K, V, S> { }
+unsafe impl<T, S: AliasSafe> AliasSafe for std::collections::HashSet<T, S> { }
+unsafe impl AliasSafe for std::collections::hash_map::RandomState { }
+unsafe impl<H> AliasSafe for std::hash::BuildHasherDefault<H> { }
+unsafe impl AliasSafe for std::ffi::OsString { }
+unsafe impl AliasSafe for std::path::PathBuf { }
+
+/// A region that retains clones of items, for types without a columnar layout.
+///
+/// Each copied item is cloned into a spine owned by the region, and a bitwise
+/// alias of the clone is returned. The clones are dropped when the region is
+/// cleared or dropped, which releases whatever resources they own.
+pub struct CloneRegion<T> {
+    /// Clones of all copied items, which own their resources.
+    spine: Vec<T>,
+}
+
+impl<T> Default for CloneRegion<T> {
+    fn default() -> Self {
+        Self { spine: Vec::new() }
+    }
+}
+
+impl<T: Clone + AliasSafe> Region for CloneRegion<T> {
+    type Item = T;
+    #[inline]
+    unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+        self.spine.push(item.clone());
+        // Unsafety justified in that the spine retains the owned instance, and only
+        // drops it once the caller has promised to discard the alias (`clear`).
+        std::ptr::read(self.spine.last().unwrap())
+    }
+    #[inline]
+    fn clear(&mut self) {
+        self.spine.clear();
+    }
+    #[inline]
+    fn checkpoint(&self, marks: &mut Vec<usize>) {
+        marks.push(self.spine.len());
+    }
+    #[inline]
+    unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+        // Dropping the clones releases their resources.
+        self.spine.truncate(*marks.next().unwrap());
+    }
+
+    #[inline]
+    fn item_heap_size(_item: &Self::Item) -> usize {
+        // Only the clone in the spine is accounted for, as with `heap_size`.
+        std::mem::size_of::<T>()
+    }
+    fn reserve_items<'a, I>(&mut self, items: I)
+    where
+        Self: 'a,
+        I: Iterator<Item=&'a Self::Item> + Clone
+    {
+        self.spine.reserve(items.count());
+    }
+
+    fn reserve_regions<'a, I>(&mut self, regions: I)
+    where
+        Self: 'a,
+        I: Iterator<Item = &'a Self> + Clone
+    {
+        self.spine.reserve(regions.map(|r| r.spine.len()).sum());
+    }
+
+    #[inline]
+    fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+        with_segment(path, ".clone.spine", |path| {
+            let size_of_t = std::mem::size_of::<T>();
+            callback(path, self.spine.len() * size_of_t, self.spine.capacity() * size_of_t);
+        });
+    }
+    #[inline]
+    fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+        // Allocations owned by the clones themselves are not visible to us.
+        let size_of_t = std::mem::size_of::<T>();
+        callback(self.spine.len() * size_of_t, self.spine.capacity() * size_of_t);
+    }
+}
+
+impl<T: Clone + AliasSafe> RegionCapacity for CloneRegion<T> {
+    type Capacity = usize;
+    #[inline]
+    fn measure_item(_item: &Self::Item) -> Self::Capacity {
+        1
+    }
+    #[inline]
+    fn measure(&self) -> Self::Capacity {
+        self.spine.len()
+    }
+    #[inline]
+    fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+        self.spine.reserve(*capacity);
+    }
+}
+
+/// A wrapper that opts a type in to storage by [CloneRegion].
+///
+/// This allows fields of types that do not implement [Columnation], and which
+/// cannot be given an implementation (e.g. due to coherence), to appear in
+/// records stored in a [ColumnStack]. The type must implement [AliasSafe].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cloned<T>(pub T);
+
+unsafe impl<T: AliasSafe> AliasSafe for Cloned<T> { }
+
+impl<T> std::ops::Deref for Cloned<T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Cloned<T> {
+    fn from(item: T) -> Self {
+        Cloned(item)
+    }
+}
+
+/// A region that deduplicates items before copying them in to an inner region.
+///
+/// Each copied item is looked up in a hash table of previously copied items. If an
+/// equal item is found, another alias of its falsified allocation is returned, and
+/// otherwise the item is copied in to the inner region and recorded in the table.
+pub struct InterningRegion<R: Region> {
+    /// Region that holds the distinct items.
+    region: R,
+    /// Aliases of all distinct items copied in to `region`, and the order in which they were copied.
+    table: std::collections::HashMap<interning::Alias<R::Item>, usize>,
+    /// Number of copies satisfied by an existing item.
+    hits: usize,
+    /// Number of copies that required a new item.
+    misses: usize,
+}
+
+impl<R: Region> Default for InterningRegion<R> {
+    fn default() -> Self {
+        Self {
+            region: R::default(),
+            table: Default::default(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<R: Region> InterningRegion<R> {
+    /// The number of copies that returned an alias of an existing item.
+    ///
+    /// Statistics accumulate across calls to `clear`.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+    /// The number of copies that copied an item in to the inner region.
+    ///
+    /// Statistics accumulate across calls to `clear`.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+    /// Estimated used and allocated bytes of the hash table, as `(size, capacity)`.
+    ///
+    /// This counts one entry per item and per slot of capacity, and not the table's
+    /// internal overhead, which depends on the standard library's implementation.
+    fn table_heap_size(&self) -> (usize, usize) {
+        let size_of_entry = std::mem::size_of::<(interning::Alias<R::Item>, usize)>();
+        (self.table.len() * size_of_entry, self.table.capacity() * size_of_entry)
+    }
+}
+
+impl<R: Region> Region for InterningRegion<R>
+where
+    R::Item: std::hash::Hash + Eq,
+{
+    type Item = R::Item;
+    const SHARES_MEMORY: bool = true;
+    #[inline]
+    unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+        if let Some((alias, _)) = self.table.get_key_value(item) {
+            self.hits += 1;
+            alias.read()
+        } else {
+            self.misses += 1;
+            let copy = self.region.copy(item);
+            let alias = interning::Alias::new(&copy);
+            let order = self.table.len();
+            self.table.insert(alias, order);
+            copy
+        }
+    }
+    #[inline]
+    fn clear(&mut self) {
+        // The table only holds aliases, which must not be dropped.
+        self.table.clear();
+        self.region.clear();
+    }
+    #[inline]
+    fn clear_retaining(&mut self) {
+        self.table.clear();
+        self.region.clear_retaining();
+    }
+    #[inline]
+    fn stashed_chunks(&self) -> usize {
+        self.region.stashed_chunks()
+    }
+    #[cfg(feature = "hooks")]
+    #[inline]
+    fn set_hook(&mut self, hook: Option<&RegionHook>) {
+        self.region.set_hook(hook);
+    }
+    #[inline]
+    fn checkpoint(&self, marks: &mut Vec<usize>) {
+        marks.push(self.table.len());
+        self.region.checkpoint(marks);
+    }
+    #[inline]
+    unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+        // Forget items copied since the checkpoint, as their aliases are about to be invalidated.
+        let len = *marks.next().unwrap();
+        self.table.retain(|_, order| *order < len);
+        self.region.rollback(marks);
+    }
+
+    #[inline]
+    fn item_heap_size(item: &Self::Item) -> usize {
+        // Duplicates use no memory, but we cannot know which items are duplicates.
+        R::item_heap_size(item)
+    }
+    fn reserve_items<'a, I>(&mut self, items: I)
+    where
+        Self: 'a,
+        I: Iterator<Item=&'a Self::Item> + Clone
+    {
+        // Without hashing, we cannot know which items are duplicates, and so reserve for all.
+        self.table.reserve(items.clone().count());
+        self.region.reserve_items(items);
+    }
+
+    fn reserve_regions<'a, I>(&mut self, regions: I)
+    where
+        Self: 'a,
+        I: Iterator<Item = &'a Self> + Clone
+    {
+        self.table.reserve(regions.clone().map(|r| r.table.len()).sum());
+        self.region.reserve_regions(regions.map(|r| &r.region));
+    }
+
+    #[inline]
+    fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+        with_segment(path, ".interning", |path| {
+            self.region.heap_size_paths(path, &mut callback);
+            with_segment(path, ".table", |path| {
+                let (size, capacity) = self.table_heap_size();
+                callback(path, size, capacity);
+            });
+        });
+    }
+    #[inline]
+    fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+        let (size, capacity) = self.table_heap_size();
+        callback(size, capacity);
+        self.region.heap_size(callback);
+    }
+}
+
+impl<R: RegionCapacity> RegionCapacity for InterningRegion<R>
+where
+    R::Item: std::hash::Hash + Eq,
+{
+    type Capacity = (usize, R::Capacity);
+    #[inline]
+    fn measure_item(item: &Self::Item) -> Self::Capacity {
+        (1, R::measure_item(item))
+    }
+    #[inline]
+    fn measure(&self) -> Self::Capacity {
+        (self.table.len(), self.region.measure())
+    }
+    #[inline]
+    fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+        self.table.reserve(capacity.0);
+        self.region.reserve_capacity(&capacity.1);
+    }
+}
+
+mod interning {
+
+    use std::mem::ManuallyDrop;
+
+    /// A bitwise alias of an item owned by a region.
+    ///
+    /// The alias is never dropped, and delegates comparison and hashing to the item.
+    pub struct Alias<T> {
+        item: ManuallyDrop<T>,
+    }
+
+    impl<T> Alias<T> {
+        /// Creates an alias of `item`.
+        ///
+        /// # Safety
+        ///
+        /// The alias must not be used once `item`'s resources are invalidated.
+        pub unsafe fn new(item: &T) -> Self {
+            Self { item: ManuallyDrop::new(std::ptr::read(item)) }
+        }
+        /// Produces another alias of the item.
+        ///
+        /// # Safety
+        ///
+        /// The result must not be dropped, and is subject to the same constraints as `self`.
+        pub unsafe fn read(&self) -> T {
+            std::ptr::read(&*self.item)
+        }
+    }
+
+    impl<T> std::borrow::Borrow<T> for Alias<T> {
+        fn borrow(&self) -> &T {
+            &self.item
+        }
+    }
+
+    impl<T: PartialEq> PartialEq for Alias<T> {
+        fn eq(&self, other: &Self) -> bool {
+            *self.item == *other.item
+        }
+    }
+
+    impl<T: Eq> Eq for Alias<T> {}
+
+    impl<T: std::hash::Hash> std::hash::Hash for Alias<T> {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            self.item.hash(state)
+        }
+    }
+}
+
+/// A wrapper that opts a type in to deduplicated storage by [InterningRegion].
+///
+/// This suits highly repetitive data, like `Interned<String>` for country codes or status values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interned<T>(pub T);
+
+impl<T> std::ops::Deref for Interned<T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Interned<T> {
+    fn from(item: T) -> Self {
+        Interned(item)
+    }
+}
+
 
 /// A region allocator which holds items at stable memory locations.
 ///
@@ -137,8 +716,13 @@ pub struct StableRegion<T> {
     local: Vec<T>,
     /// All previously active allocations.
     stash: Vec<Vec<T>>,
+    /// Empty allocations retained by `clear_retaining`, for reuse.
+    spare: Vec<Vec<T>>,
     /// The maximum allocation size
     limit: usize,
+    /// A hook to observe allocations, if any.
+    #[cfg(feature = "hooks")]
+    hook: Option<RegionHook>,
 }
 
 // Manually implement `Default` as `T` may not implement it.
@@ -147,7 +731,10 @@ impl<T> Default for StableRegion<T> {
         Self {
             local: Vec::new(),
             stash: Vec::new(),
+            spare: Vec::new(),
             limit: usize::MAX,
+            #[cfg(feature = "hooks")]
+            hook: None,
         }
     }
 }
@@ -158,13 +745,41 @@ impl<T> StableRegion<T> {
         Self {
             local: Default::default(),
             stash: Default::default(),
-            limit: limit,
+            spare: Default::default(),
+            limit,
+            #[cfg(feature = "hooks")]
+            hook: None,
         }
     }
 
+    /// The number of allocations the region has filled and stashed.
+    #[inline]
+    pub fn stashed_chunks(&self) -> usize {
+        self.stash.len()
+    }
+
+    /// Installs `hook` to observe the allocations of the region, or removes it if `None`.
+    #[cfg(feature = "hooks")]
+    pub fn set_hook(&mut self, hook: Option<&RegionHook>) {
+        self.hook = hook.cloned();
+    }
+
+    /// The bytes allocated by the region.
+    #[cfg(feature = "hooks")]
+    fn allocated_bytes(&self) -> usize {
+        let size_of_t = std::mem::size_of::<T>();
+        let chunks = self.stash.iter().chain(self.spare.iter());
+        self.local.capacity() * size_of_t + chunks.map(|s| s.capacity() * size_of_t).sum::<usize>()
+    }
+
     /// Clears the contents without dropping any elements.
     #[inline]
     pub fn clear(&mut self) {
+        #[cfg(feature = "hooks")]
+        if let Some(hook) = &self.hook {
+            let retained = self.local.capacity() * std::mem::size_of::<T>();
+            hook.fire(RegionEvent::Clear { released: self.allocated_bytes() - retained, retained });
+        }
         unsafe {
             // Unsafety justified in that setting the length to zero exposes
             // no invalid data.
@@ -174,22 +789,77 @@ impl<T> StableRegion<T> {
                 buffer.set_len(0);
             }
         }
+        self.spare.clear();
     }
-    /// Copies an iterator of items into the region.
-    #[inline]
-    pub fn copy_iter<I>(&mut self, items: I) -> &mut [T]
-    where
-        I: Iterator<Item = T> + std::iter::ExactSizeIterator,
-    {
-        self.reserve(items.len());
-        let initial_len = self.local.len();
-        self.local.extend(items);
-        &mut self.local[initial_len ..]
-    }
-    /// Copies a slice of cloneable items into the region.
+    /// Clears the contents without dropping any elements, and retains all allocations for reuse.
     #[inline]
-    pub fn copy_slice(&mut self, items: &[T]) -> &mut [T]
-    where
+    pub fn clear_retaining(&mut self) {
+        #[cfg(feature = "hooks")]
+        if let Some(hook) = &self.hook {
+            hook.fire(RegionEvent::Clear { released: 0, retained: self.allocated_bytes() });
+        }
+        unsafe {
+            // Unsafety justified in that setting the length to zero exposes
+            // no invalid data.
+            self.local.set_len(0);
+            // Retain allocations in `stash` without dropping their elements.
+            for mut buffer in self.stash.drain(..) {
+                buffer.set_len(0);
+                self.spare.push(buffer);
+            }
+        }
+    }
+    /// Records the current position of the region, to which it can later be rolled back.
+    #[inline]
+    pub fn checkpoint(&self, marks: &mut Vec<usize>) {
+        marks.push(self.stash.len());
+        marks.push(self.local.len());
+    }
+    /// Discards items added since a position recorded by `checkpoint`, without dropping them.
+    ///
+    /// Allocations made since the position are released.
+    ///
+    /// # Safety
+    ///
+    /// The marks must have been recorded by this region, which must not have been cleared
+    /// or rolled back to an earlier position in the meantime. References to items added since
+    /// the checkpoint are invalidated.
+    #[inline]
+    pub unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+        let stash_len = *marks.next().unwrap();
+        let local_len = *marks.next().unwrap();
+        if self.stash.len() > stash_len {
+            #[cfg(feature = "hooks")]
+            if let Some(hook) = &self.hook {
+                let size_of_t = std::mem::size_of::<T>();
+                for buffer in self.stash[stash_len + 1 ..].iter().chain(std::iter::once(&self.local)) {
+                    hook.fire(RegionEvent::Release { bytes: buffer.capacity() * size_of_t });
+                }
+            }
+            // The allocation that was active at the checkpoint has since been stashed.
+            self.local.set_len(0);
+            for mut buffer in self.stash.drain(stash_len + 1 ..) {
+                buffer.set_len(0);
+            }
+            self.local = self.stash.pop().unwrap();
+        }
+        self.local.set_len(local_len);
+    }
+    /// Copies an iterator of items into the region.
+    #[inline]
+    pub fn copy_iter<I>(&mut self, items: I) -> &mut [T]
+    where
+        I: Iterator<Item = T> + std::iter::ExactSizeIterator,
+    {
+        self.reserve(items.len());
+        let initial_len = self.local.len();
+        self.local.extend(items);
+        &mut self.local[initial_len ..]
+    }
+    /// Copies a slice of cloneable items into the region.
+    #[inline]
+    pub fn copy_slice(&mut self, items: &[T]) -> &mut [T]
+    where
         T: Clone,
     {
         self.reserve(items.len());
@@ -204,18 +874,44 @@ impl<T> StableRegion<T> {
         // Check if `item` fits into `self.local` without reallocation.
         // If not, stash `self.local` and increase the allocation.
         if count > self.local.capacity() - self.local.len() {
-            // Increase allocated capacity in powers of two.
-            // We could choose a different rule here if we wanted to be
-            // more conservative with memory (e.g. page size allocations).
-            let mut next_len = (self.local.capacity() + 1).next_power_of_two();
-            next_len = std::cmp::min(next_len, self.limit);
-            next_len = std::cmp::max(count, next_len);
-            let new_local = Vec::with_capacity(next_len);
+            // Reuse the first spare allocation large enough, which retains the order of growth.
+            let spare = self.spare.iter().position(|buffer| buffer.capacity() >= count);
+            #[cfg(feature = "hooks")]
+            let reused = spare.is_some();
+            let new_local = match spare {
+                Some(index) => self.spare.remove(index),
+                None => {
+                    // Increase allocated capacity in powers of two.
+                    // We could choose a different rule here if we wanted to be
+                    // more conservative with memory (e.g. page size allocations).
+                    let mut next_len = (self.local.capacity() + 1).next_power_of_two();
+                    next_len = std::cmp::min(next_len, self.limit);
+                    next_len = std::cmp::max(count, next_len);
+                    Vec::with_capacity(next_len)
+                }
+            };
             if self.local.is_empty() {
+                #[cfg(feature = "hooks")]
+                if let Some(hook) = &self.hook {
+                    if self.local.capacity() > 0 {
+                        hook.fire(RegionEvent::Release { bytes: self.local.capacity() * std::mem::size_of::<T>() });
+                    }
+                }
                 self.local = new_local;
             } else {
+                #[cfg(feature = "hooks")]
+                if let Some(hook) = &self.hook {
+                    hook.fire(RegionEvent::Stash { bytes: self.local.capacity() * std::mem::size_of::<T>() });
+                }
                 self.stash.push(std::mem::replace(&mut self.local, new_local));
             }
+            #[cfg(feature = "hooks")]
+            if let Some(hook) = &self.hook {
+                if !reused {
+                    let bytes = self.local.capacity() * std::mem::size_of::<T>();
+                    hook.fire(RegionEvent::Allocate { bytes, total: self.allocated_bytes() });
+                }
+            }
         }
     }
 
@@ -226,10 +922,29 @@ impl<T> StableRegion<T> {
         region
     }
 
+    /// The items in the `index`-th allocation, where the active allocation follows the stash.
+    #[inline]
+    pub(crate) fn chunk(&self, index: usize) -> &[T] {
+        if index < self.stash.len() { &self.stash[index] } else { &self.local }
+    }
+    /// Drops all items, for regions that own their items rather than hold falsified copies.
+    ///
+    /// Allocations are not released; follow with `clear` for that.
+    pub(crate) fn drop_items(&mut self) {
+        self.local.clear();
+        for buffer in self.stash.iter_mut() {
+            buffer.clear();
+        }
+    }
+
     /// The number of items current held in the region.
     pub fn len(&self) -> usize {
         self.local.len() + self.stash.iter().map(|r| r.len()).sum::<usize>()
     }
+    /// Returns `true` if the region holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.local.is_empty() && self.stash.iter().all(|r| r.is_empty())
+    }
 
     #[inline]
     pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
@@ -246,9 +961,75 @@ impl<T> StableRegion<T> {
         for stash in &self.stash {
             callback(stash.len() * size_of_t, stash.capacity() * size_of_t);
         }
+        if self.spare.capacity() > 0 {
+            callback(0, self.spare.capacity() * std::mem::size_of::<Vec<T>>());
+            for spare in &self.spare {
+                callback(0, spare.capacity() * size_of_t);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "hooks")]
+impl<T> Drop for StableRegion<T> {
+    fn drop(&mut self) {
+        if let Some(hook) = &self.hook {
+            hook.fire(RegionEvent::Drop { bytes: self.allocated_bytes() });
+        }
+    }
+}
+
+/// An event in the life of a [StableRegion], observed by a [RegionHook].
+///
+/// Byte sizes are those of allocated capacity.
+#[cfg(feature = "hooks")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegionEvent {
+    /// A chunk of `bytes` bytes was allocated, after which the region holds `total` bytes.
+    Allocate { bytes: usize, total: usize },
+    /// The active chunk, of `bytes` bytes, was moved to the stash to make room for a new one.
+    Stash { bytes: usize },
+    /// The region was cleared, releasing `released` bytes of its stashed and spare chunks,
+    /// and retaining `retained` bytes for reuse.
+    Clear { released: usize, retained: usize },
+    /// A chunk of `bytes` bytes was released, either by a rollback past its allocation
+    /// or because it was empty and too small for the next item.
+    Release { bytes: usize },
+    /// The region was dropped, releasing `bytes` bytes.
+    Drop { bytes: usize },
+}
+
+/// A callback that observes the allocations of regions.
+///
+/// Installed in regions with [Region::set_hook], or in stacks with [ColumnStack::set_hook].
+/// Regions only consult their hook when they allocate, are cleared, or are dropped, and
+/// so without a hook there is no cost to copying items. Hooks require the `hooks` feature,
+/// without which regions have neither the hook nor the `Drop` implementation that reports it.
+#[cfg(feature = "hooks")]
+#[derive(Clone)]
+pub struct RegionHook {
+    callback: std::sync::Arc<dyn Fn(RegionEvent) + Send + Sync>,
+}
+
+#[cfg(feature = "hooks")]
+impl RegionHook {
+    /// Construct a [RegionHook] that calls `callback` for each event.
+    pub fn new(callback: impl Fn(RegionEvent) + Send + Sync + 'static) -> Self {
+        Self { callback: std::sync::Arc::new(callback) }
+    }
+    /// Calls the callback for `event`.
+    #[inline]
+    pub fn fire(&self, event: RegionEvent) {
+        (self.callback)(event)
     }
 }
 
+#[cfg(feature = "hooks")]
+impl std::fmt::Debug for RegionHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegionHook").finish_non_exhaustive()
+    }
+}
 
 /// A type that can be stored in a columnar region.
 ///
@@ -261,11 +1042,13 @@ pub trait Columnation: Sized {
     type InnerRegion: Region<Item = Self>;
 }
 
-pub use columnstack::ColumnStack;
+pub use columnstack::{Checkpoint, ColumnStack, ColumnStackStats};
 
 mod columnstack {
 
-    use super::{Columnation, Region};
+    use super::{Columnation, HeapReport, Region, RegionCapacity, TrivialRegion, TupleIndex};
+    #[cfg(feature = "hooks")]
+    use super::RegionHook;
 
     /// An append-only vector that store records as columns.
     ///
@@ -279,6 +1062,99 @@ mod columnstack {
     pub struct ColumnStack<T: Columnation> {
         pub(crate) local: Vec<T>,
         pub(crate) inner: T::InnerRegion,
+        /// Region bytes used by replaced elements, which compaction would reclaim.
+        pub(crate) garbage: usize,
+        /// Fraction of region bytes that may be garbage before compaction, if any.
+        pub(crate) compact_fraction: Option<f64>,
+        /// Region bytes used by the first `measured` elements, and by garbage.
+        pub(crate) used: usize,
+        /// The number of leading elements whose region bytes are counted in `used`.
+        pub(crate) measured: usize,
+        /// Incremented whenever the region is replaced or emptied, which invalidates checkpoints.
+        pub(crate) generation: usize,
+        /// The least index of an element replaced or removed in this generation, if any.
+        ///
+        /// Checkpoints of more elements cannot roll the region back, as it would reclaim
+        /// memory that retained elements reference.
+        pub(crate) floor: usize,
+        /// A hook installed in the region, if any.
+        #[cfg(feature = "hooks")]
+        pub(crate) hook: Option<RegionHook>,
+    }
+
+    /// Statistics of the memory of a [ColumnStack], obtained from [ColumnStack::stats].
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct ColumnStackStats {
+        /// Bytes of the spine holding elements.
+        pub spine_bytes: usize,
+        /// Bytes of the spine allocated but not in use.
+        pub spine_unused_bytes: usize,
+        /// Region bytes reachable from elements, including the regions' bookkeeping.
+        pub live_bytes: usize,
+        /// Region bytes of elements that were replaced or removed, which compaction would reclaim.
+        pub dead_bytes: usize,
+        /// Region bytes allocated but not yet used, for example at the tails of chunks.
+        pub unused_bytes: usize,
+        /// The number of region allocations.
+        pub chunks: usize,
+        /// The number of region allocations that have been filled and stashed.
+        pub stashed_chunks: usize,
+    }
+
+    /// A position of a [ColumnStack], to which it can be rolled back.
+    ///
+    /// Obtained from [ColumnStack::checkpoint], and dereferences to the stack, which it
+    /// borrows until dropped. Dropping a checkpoint keeps the elements added since.
+    pub struct Checkpoint<'a, T: Columnation> {
+        stack: &'a mut ColumnStack<T>,
+        /// The number of elements at the checkpoint.
+        len: usize,
+        /// Garbage bytes at the checkpoint.
+        garbage: usize,
+        /// The generation of the stack at the checkpoint.
+        generation: usize,
+        /// The position of the region at the checkpoint.
+        marks: Vec<usize>,
+    }
+
+    impl<'a, T: Columnation> Checkpoint<'a, T> {
+        /// Discards all elements added since the checkpoint.
+        ///
+        /// The region is rolled back to its position at the checkpoint, which reclaims the
+        /// memory of the discarded elements. If an element before the checkpoint has since been
+        /// replaced or removed, or the stack compacted or cleared, the region bytes of the
+        /// discarded elements are instead counted as garbage, as for [ColumnStack::truncate].
+        pub fn rollback(&mut self) {
+            let stack = &mut *self.stack;
+            if self.len == 0 {
+                stack.clear();
+            } else if self.generation == stack.generation && self.len <= stack.floor {
+                unsafe {
+                    // Unsafety justified in that no element before `len` has been replaced or
+                    // removed since the position was recorded, nor the region replaced, and so
+                    // the retained elements reference only region memory from before it.
+                    stack.rewind(self.len, &self.marks, self.garbage);
+                }
+                stack.maybe_compact();
+            } else {
+                stack.truncate(self.len);
+            }
+        }
+    }
+
+    impl<'a, T: Columnation> std::ops::Deref for Checkpoint<'a, T> {
+        type Target = ColumnStack<T>;
+        #[inline(always)]
+        fn deref(&self) -> &Self::Target {
+            self.stack
+        }
+    }
+
+    impl<'a, T: Columnation> std::ops::DerefMut for Checkpoint<'a, T> {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.stack
+        }
     }
 
     impl<T: Columnation> ColumnStack<T> {
@@ -288,10 +1164,9 @@ mod columnstack {
         /// because we can't generally know how much space would be required. For this reason,
         /// this function is private.
         fn with_capacity(capacity: usize) -> Self {
-            Self {
-                local: Vec::with_capacity(capacity),
-                inner: T::InnerRegion::default(),
-            }
+            let mut stack = Self::default();
+            stack.local.reserve(capacity);
+            stack
         }
 
         /// Ensures `Self` can absorb `items` without further allocations.
@@ -321,6 +1196,31 @@ mod columnstack {
             self.inner.reserve_regions(regions.map(|cs| &cs.inner));
         }
 
+        /// The capacity that the elements of `Self` use, as the number of elements and the
+        /// capacity of their region.
+        ///
+        /// The result can be stored, combined and scaled, and then applied to other stacks
+        /// with [reserve_capacity](Self::reserve_capacity).
+        pub fn measure(&self) -> (usize, <T::InnerRegion as RegionCapacity>::Capacity)
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            (self.local.len(), self.inner.measure())
+        }
+
+        /// Ensures `Self` can absorb elements of total capacity `capacity` without further allocations.
+        pub fn reserve_capacity(&mut self, capacity: &(usize, <T::InnerRegion as RegionCapacity>::Capacity))
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            self.local.reserve(capacity.0);
+            self.inner.reserve_capacity(&capacity.1);
+        }
+        /// The bytes that copying `item` in to the stack uses, in the spine and the region.
+        pub(crate) fn element_bytes(&self, item: &T) -> usize {
+            std::mem::size_of::<T>() + T::InnerRegion::item_heap_size(item)
+        }
+
 
         /// Copies an element in to the region.
         ///
@@ -332,6 +1232,198 @@ mod columnstack {
                 self.local.push(self.inner.copy(item));
             }
         }
+        /// Replaces the element at `index` with a copy of `item`.
+        ///
+        /// The region bytes of the prior element are not reclaimed, but are counted as
+        /// garbage. If garbage exceeds the compaction fraction, if any, the stack is compacted.
+        ///
+        /// Panics if `index` is out of bounds.
+        pub fn replace(&mut self, index: usize, item: &T) {
+            self.measure_used();
+            self.garbage += Self::reclaimable_bytes(&self.local[index]);
+            self.used += Self::reclaimable_bytes(item);
+            unsafe {
+                // Unsafety justified in that the prior element does not own its resources,
+                // and must not be dropped.
+                let copy = self.inner.copy(item);
+                std::ptr::write(&mut self.local[index], copy);
+            }
+            // Rolling back past the element would reclaim its replacement.
+            self.floor = self.floor.min(index);
+            self.maybe_compact();
+        }
+        /// Replaces the element at `index` with the result of `logic` applied to it.
+        ///
+        /// Panics if `index` is out of bounds.
+        pub fn update_with<F: FnOnce(&T) -> T>(&mut self, index: usize, logic: F) {
+            let item = logic(&self.local[index]);
+            self.replace(index, &item);
+        }
+        /// Mutable access to the elements, for types whose region is trivial.
+        ///
+        /// Such elements own no resources and reference no region memory, and so can be
+        /// modified in place.
+        #[inline]
+        pub fn as_mut_slice(&mut self) -> &mut [T]
+        where
+            T::InnerRegion: TrivialRegion,
+        {
+            &mut self.local[..]
+        }
+        /// Mutable access to the `I`-th component of each tuple, for components whose region is trivial.
+        ///
+        /// Such components own no resources and reference no region memory, and so can be
+        /// modified in place, even if other components (e.g. `String`s) cannot.
+        #[inline]
+        pub fn column_mut<const I: usize>(&mut self) -> impl ExactSizeIterator<Item = &mut <T as TupleIndex<I>>::Item>
+        where
+            T: TupleIndex<I>,
+            <T as TupleIndex<I>>::Item: Columnation,
+            <<T as TupleIndex<I>>::Item as Columnation>::InnerRegion: TrivialRegion,
+        {
+            self.local.iter_mut().map(|element| element.index_mut())
+        }
+        /// Region bytes used by replaced elements, which compaction would reclaim.
+        ///
+        /// Regions whose items share memory, as reported by [Region::SHARES_MEMORY], cannot
+        /// attribute bytes to elements, and report no garbage.
+        #[inline]
+        pub fn garbage_bytes(&self) -> usize {
+            self.garbage
+        }
+        /// Sets the fraction of garbage region bytes that triggers compaction.
+        ///
+        /// A value of `None`, the default, disables automatic compaction. Stacks whose regions
+        /// share memory report no garbage, and so are only compacted on request.
+        pub fn set_compact_fraction(&mut self, fraction: Option<f64>) {
+            self.compact_fraction = fraction;
+        }
+        /// Records the current position of the stack, to which it can later be rolled back.
+        ///
+        /// The checkpoint borrows the stack, which remains accessible through it, and so
+        /// checkpoints nest and are rolled back in the reverse order of their creation.
+        pub fn checkpoint(&mut self) -> Checkpoint<'_, T> {
+            let mut marks = Vec::new();
+            self.inner.checkpoint(&mut marks);
+            Checkpoint {
+                len: self.local.len(),
+                garbage: self.garbage,
+                generation: self.generation,
+                marks,
+                stack: self,
+            }
+        }
+        /// Shortens the stack to `len` elements, if it is longer.
+        ///
+        /// This method does not reclaim memory in the inner region, but counts the region bytes
+        /// of discarded elements as garbage, which compaction would reclaim. If garbage exceeds
+        /// the compaction fraction, if any, the stack is compacted. Use a [Checkpoint] to
+        /// reclaim the memory of elements added since it.
+        pub fn truncate(&mut self, len: usize) {
+            if len < self.local.len() {
+                if len == 0 {
+                    self.clear();
+                } else {
+                    self.measure_used();
+                    self.garbage += self.local[len ..].iter().map(Self::reclaimable_bytes).sum::<usize>();
+                    self.measured = len;
+                    unsafe {
+                        // Unsafety justified in that `len` is less than `self.local.len()` and
+                        // so this exposes no invalid data.
+                        self.local.set_len(len);
+                    }
+                    self.floor = self.floor.min(len);
+                    self.maybe_compact();
+                }
+            }
+        }
+        /// Discards the elements from `len` on, and rolls the region back to `position`.
+        ///
+        /// # Safety
+        ///
+        /// The region must have recorded `position` when the stack held `len` elements and
+        /// `garbage` garbage bytes, in the current generation, and no element before `len` may
+        /// have been replaced or removed since.
+        unsafe fn rewind(&mut self, len: usize, position: &[usize], garbage: usize) {
+            // Elements and garbage since the position are in the reclaimed region memory.
+            self.measure_used();
+            self.used -= self.local[len ..].iter().map(Self::reclaimable_bytes).sum::<usize>();
+            self.used -= self.garbage - garbage;
+            self.measured = len;
+            self.local.set_len(len);
+            self.inner.rollback(&mut position.iter());
+            self.garbage = garbage;
+        }
+        /// Removes the last element, returning `true` if there was one.
+        ///
+        /// The element cannot be returned, as it does not own its resources.
+        pub fn pop(&mut self) -> bool {
+            match self.local.len() {
+                0 => false,
+                len => {
+                    self.truncate(len - 1);
+                    true
+                }
+            }
+        }
+        /// Compacts the stack if garbage exceeds the compaction fraction.
+        fn maybe_compact(&mut self) {
+            if let Some(fraction) = self.compact_fraction {
+                self.measure_used();
+                if self.garbage > 0 && self.garbage as f64 > fraction * self.used as f64 {
+                    self.compact();
+                }
+            }
+        }
+        /// The region bytes that discarding `element` would make reclaimable by compaction.
+        ///
+        /// This is zero for regions that share memory, whose items may use bytes that
+        /// other items also use.
+        #[inline]
+        fn reclaimable_bytes(element: &T) -> usize {
+            if T::InnerRegion::SHARES_MEMORY {
+                0
+            } else {
+                T::InnerRegion::item_heap_size(element)
+            }
+        }
+        /// Counts the region bytes of elements not yet measured in `used`.
+        ///
+        /// Each element is measured at most once, rather than on every copy, so that copying
+        /// does not pay for garbage tracking.
+        fn measure_used(&mut self) {
+            self.used += self.local[self.measured ..].iter().map(Self::reclaimable_bytes).sum::<usize>();
+            self.measured = self.local.len();
+        }
+
+        /// Installs `hook` in the region, to observe its allocations, or removes it if `None`.
+        ///
+        /// The hook remains installed across compaction.
+        #[cfg(feature = "hooks")]
+        pub fn set_hook(&mut self, hook: Option<RegionHook>) {
+            self.inner.set_hook(hook.as_ref());
+            self.hook = hook;
+        }
+        /// Copies all elements in to a fresh region, and releases the current region.
+        pub fn compact(&mut self) {
+            let mut region = T::InnerRegion::default();
+            #[cfg(feature = "hooks")]
+            region.set_hook(self.hook.as_ref());
+            region.reserve_items(self.local.iter());
+            for element in self.local.iter_mut() {
+                unsafe {
+                    // Unsafety justified in that the prior element does not own its
+                    // resources, and is discarded with the prior region.
+                    let copy = region.copy(element);
+                    std::ptr::write(element, copy);
+                }
+            }
+            self.forget_region();
+            std::mem::swap(&mut self.inner, &mut region);
+            region.clear();
+            self.used -= self.garbage;
+            self.garbage = 0;
+        }
         /// Empties the collection.
         pub fn clear(&mut self) {
             unsafe {
@@ -340,18 +1432,49 @@ mod columnstack {
                 self.local.set_len(0);
                 self.inner.clear();
             }
+            self.forget_elements();
+        }
+        /// Empties the collection, retaining all region allocations for reuse.
+        ///
+        /// Unlike `clear`, which releases all but the active allocation of each region,
+        /// this suits stacks that are refilled with about as much data again.
+        pub fn clear_retaining(&mut self) {
+            unsafe {
+                // Unsafety justified in that setting the length to zero exposes
+                // no invalid data.
+                self.local.set_len(0);
+                self.inner.clear_retaining();
+            }
+            self.forget_elements();
+        }
+        /// Resets the accounting of garbage, once the stack is emptied.
+        fn forget_elements(&mut self) {
+            self.garbage = 0;
+            self.used = 0;
+            self.measured = 0;
+            self.forget_region();
+        }
+        /// Invalidates all checkpoints, once the region is replaced or emptied.
+        fn forget_region(&mut self) {
+            self.generation = self.generation.wrapping_add(1);
+            self.floor = usize::MAX;
         }
         /// Retain elements that pass a predicate, from a specified offset.
         ///
-        /// This method may or may not reclaim memory in the inner region.
+        /// This method does not reclaim memory in the inner region, but counts the region bytes
+        /// of removed elements as garbage, which compaction would reclaim. If garbage exceeds
+        /// the compaction fraction, if any, the stack is compacted.
         pub fn retain_from<P: FnMut(&T)->bool>(&mut self, index: usize, mut predicate: P) {
             if index < self.local.len() {
+                self.measure_used();
                 let mut write_position = index;
                 for position in index .. self.local.len() {
                     if predicate(&self[position]) {
                         // TODO: compact the inner region and update pointers.
                         self.local.swap(position, write_position);
                         write_position += 1;
+                    } else {
+                        self.garbage += Self::reclaimable_bytes(&self.local[position]);
                     }
                 }
                 unsafe {
@@ -359,7 +1482,122 @@ mod columnstack {
                     // `self.local.len()` and so this exposes no invalid data.
                     self.local.set_len(write_position);
                 }
+                self.measured = write_position;
+                // Rolling back past moved elements would retain the wrong elements.
+                self.floor = self.floor.min(index);
+                self.maybe_compact();
+            }
+        }
+
+        /// Copies the elements at `indices`, in order, in to a new stack.
+        ///
+        /// The new stack is sized exactly, and its region is laid out in output order.
+        ///
+        /// Panics if any index is out of bounds.
+        pub fn gather(&self, indices: &[usize]) -> ColumnStack<T> {
+            self.gather_iter(indices.iter().map(|index| &self.local[*index]))
+        }
+        /// Copies the elements whose bit is set in `mask`, in order, in to a new stack.
+        ///
+        /// Bit `i % 64` of `mask[i / 64]` selects element `i`; bits beyond the length
+        /// of the stack are ignored. The new stack is sized exactly, and its region is
+        /// laid out in output order.
+        pub fn select_by_mask(&self, mask: &[u64]) -> ColumnStack<T> {
+            self.gather_iter(
+                self.local
+                    .iter()
+                    .zip(mask.iter().flat_map(|word| (0 .. 64).map(move |bit| word >> bit & 1 == 1)))
+                    .filter(|(_, selected)| *selected)
+                    .map(|(element, _)| element)
+            )
+        }
+        /// Copies the elements in to `n` new stacks, according to `key_fn(element) % n`.
+        ///
+        /// Each output is sized exactly before elements are copied in to it.
+        ///
+        /// Panics if `n` is zero.
+        pub fn partition_into<K: FnMut(&T) -> u64>(&self, n: usize, key_fn: K) -> Vec<ColumnStack<T>> {
+            let mut outputs: Vec<_> = (0 .. n).map(|_| ColumnStack::default()).collect();
+            self.partition_extend(&mut outputs, key_fn);
+            outputs
+        }
+        /// Appends copies of the elements to `outputs`, according to `key_fn(element) % outputs.len()`.
+        ///
+        /// Each output is reserved space for its new elements before they are copied in to it.
+        ///
+        /// Panics if `outputs` is empty.
+        pub fn partition_extend<K: FnMut(&T) -> u64>(&self, outputs: &mut [ColumnStack<T>], mut key_fn: K) {
+            assert!(!outputs.is_empty(), "cannot partition in to zero outputs");
+            let mut shards = vec![Vec::new(); outputs.len()];
+            for (index, element) in self.local.iter().enumerate() {
+                shards[(key_fn(element) % outputs.len() as u64) as usize].push(index);
+            }
+            for (output, shard) in outputs.iter_mut().zip(shards) {
+                let elements = shard.iter().map(|index| &self.local[*index]);
+                output.local.reserve(shard.len());
+                output.inner.reserve_items(elements.clone());
+                for element in elements {
+                    output.copy(element);
+                }
+            }
+        }
+        /// Splits the stack in to consecutive ranges of at most `bytes` bytes each.
+        ///
+        /// The bytes of a range are those of its elements in the spine and the region.
+        /// An element larger than `bytes` is yielded as a range on its own.
+        pub fn chunks_by_bytes(&self, bytes: usize) -> impl Iterator<Item = std::ops::Range<usize>> + '_ {
+            let mut start = 0;
+            std::iter::from_fn(move || {
+                if start >= self.local.len() {
+                    return None;
+                }
+                let mut end = start;
+                let mut total = 0;
+                while let Some(element) = self.local.get(end) {
+                    let size = self.element_bytes(element);
+                    if end > start && total + size > bytes {
+                        break;
+                    }
+                    total += size;
+                    end += 1;
+                }
+                let range = start .. end;
+                start = end;
+                Some(range)
+            })
+        }
+        /// Copies the stack in to new stacks of at most `bytes` bytes each.
+        ///
+        /// See [chunks_by_bytes](Self::chunks_by_bytes) for how elements are assigned to stacks.
+        /// Each new stack is sized exactly.
+        pub fn split_into_stacks(&self, bytes: usize) -> Vec<ColumnStack<T>> {
+            self.chunks_by_bytes(bytes)
+                .map(|range| self.gather_iter(self.local[range].iter()))
+                .collect()
+        }
+        /// Copies `elements` in to a new stack, reserving exactly the required space first.
+        fn gather_iter<'a, I>(&self, elements: I) -> ColumnStack<T>
+        where
+            T: 'a,
+            I: Iterator<Item = &'a T> + Clone,
+        {
+            let mut stack = ColumnStack::<T>::with_capacity(elements.clone().count());
+            #[cfg(feature = "hooks")]
+            stack.set_hook(self.hook.clone());
+            stack.inner.reserve_items(elements.clone());
+            stack.compact_fraction = self.compact_fraction;
+            for element in elements {
+                stack.copy(element);
             }
+            stack
+        }
+
+        /// The region that holds allocations owned by the elements.
+        ///
+        /// This allows inspection of region-specific state, e.g. [InterningRegion](crate::InterningRegion) statistics.
+        #[inline]
+        pub fn region(&self) -> &T::InnerRegion {
+            &self.inner
         }
 
         /// Estimate the memory capacity in bytes.
@@ -370,6 +1608,44 @@ mod columnstack {
             self.inner.heap_size(callback);
         }
 
+        /// Statistics of the memory used, wasted and reserved by the stack.
+        ///
+        /// The cost is proportional to the number of allocations, rather than the number of elements.
+        pub fn stats(&self) -> ColumnStackStats {
+            let size_of = std::mem::size_of::<T>();
+            let (mut used, mut reserved, mut chunks) = (0, 0, 0);
+            self.inner.heap_size(|len, cap| {
+                used += len;
+                reserved += cap;
+                if cap > 0 {
+                    chunks += 1;
+                }
+            });
+            // Garbage is counted from the same item sizes that the region uses.
+            debug_assert!(self.garbage <= used, "garbage bytes exceed the region bytes used");
+            ColumnStackStats {
+                spine_bytes: self.local.len() * size_of,
+                spine_unused_bytes: (self.local.capacity() - self.local.len()) * size_of,
+                live_bytes: used.saturating_sub(self.garbage),
+                dead_bytes: self.garbage,
+                unused_bytes: reserved - used,
+                chunks,
+                stashed_chunks: self.inner.stashed_chunks(),
+            }
+        }
+
+        /// A report of the memory used and reserved by the stack, by path.
+        ///
+        /// The spine is reported at `.spine`, and the region at the paths of
+        /// [Region::heap_size_paths], starting from the empty path.
+        pub fn heap_report(&self) -> HeapReport {
+            let mut report = HeapReport::default();
+            let size_of = std::mem::size_of::<T>();
+            report.record(".spine", self.local.len() * size_of, self.local.capacity() * size_of);
+            report.record_region(&self.inner, "");
+            report
+        }
+
         /// Estimate the consumed memory capacity in bytes, summing both used and total capacity.
         #[inline]
         pub fn summed_heap_size(&self) -> (usize, usize) {
@@ -401,6 +1677,14 @@ mod columnstack {
             Self {
                 local: Vec::new(),
                 inner: T::InnerRegion::default(),
+                garbage: 0,
+                compact_fraction: None,
+                used: 0,
+                measured: 0,
+                generation: 0,
+                floor: usize::MAX,
+                #[cfg(feature = "hooks")]
+                hook: None,
             }
         }
     }
@@ -432,7 +1716,7 @@ mod columnstack {
 
     impl<T: Columnation + std::fmt::Debug> std::fmt::Debug for ColumnStack<T> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            (&self[..]).fmt(f)
+            self[..].fmt(f)
         }
     }
 
@@ -454,67 +1738,3177 @@ mod columnstack {
     }
 }
 
-mod implementations {
-
-    use super::{Region, CopyRegion, StableRegion, Columnation, ColumnStack};
-
-    // Implementations for types whose `clone()` suffices for the region.
-    macro_rules! implement_columnation {
-        ($index_type:ty) => (
-            impl Columnation for $index_type {
-                type InnerRegion = CopyRegion<$index_type>;
-            }
-        )
-    }
-    implement_columnation!(());
-    implement_columnation!(bool);
-    implement_columnation!(char);
-
-    implement_columnation!(u8);
-    implement_columnation!(u16);
-    implement_columnation!(u32);
-    implement_columnation!(u64);
-    implement_columnation!(u128);
-    implement_columnation!(usize);
+pub use chunkedstack::{ChunkedColumnStack, ChunkedVec};
 
-    implement_columnation!(i8);
-    implement_columnation!(i16);
-    implement_columnation!(i32);
-    implement_columnation!(i64);
-    implement_columnation!(i128);
-    implement_columnation!(isize);
+mod chunkedstack {
 
-    implement_columnation!(f32);
-    implement_columnation!(f64);
+    use super::{Columnation, Region};
+    #[cfg(feature = "hooks")]
+    use super::RegionHook;
 
-    implement_columnation!(std::num::Wrapping<i8>);
-    implement_columnation!(std::num::Wrapping<i16>);
-    implement_columnation!(std::num::Wrapping<i32>);
-    implement_columnation!(std::num::Wrapping<i64>);
-    implement_columnation!(std::num::Wrapping<i128>);
-    implement_columnation!(std::num::Wrapping<isize>);
+    /// An append-only vector stored as fixed-size chunks.
+    ///
+    /// Unlike a `Vec<T>`, growing this type never reallocates or moves existing
+    /// elements; it only allocates new chunks. All chunks but the last are full,
+    /// which allows indexing in constant time.
+    pub struct ChunkedVec<T> {
+        /// Chunks of elements, each of capacity `chunk_len`.
+        chunks: Vec<Vec<T>>,
+        /// The number of elements in each chunk.
+        chunk_len: usize,
+    }
 
-    implement_columnation!(std::time::Duration);
+    impl<T> Default for ChunkedVec<T> {
+        fn default() -> Self {
+            // Chunks of roughly 64KiB, and at least one element.
+            let size_of_t = std::cmp::max(std::mem::size_of::<T>(), 1);
+            Self::with_chunk_len(std::cmp::max((1 << 16) / size_of_t, 1))
+        }
+    }
 
-    /// Implementations for `Option<T: Columnation>`.
+    impl<T> ChunkedVec<T> {
+        /// Construct a [ChunkedVec] whose chunks hold `chunk_len` elements.
+        pub fn with_chunk_len(chunk_len: usize) -> Self {
+            assert!(chunk_len > 0, "chunks must hold at least one element");
+            Self {
+                chunks: Vec::new(),
+                chunk_len,
+            }
+        }
+        /// The number of elements in each chunk.
+        #[inline]
+        pub fn chunk_len(&self) -> usize {
+            self.chunk_len
+        }
+        /// Appends an element, allocating a new chunk if the last is full.
+        #[inline]
+        pub fn push(&mut self, item: T) {
+            match self.chunks.last_mut() {
+                Some(chunk) if chunk.len() < self.chunk_len => chunk.push(item),
+                _ => {
+                    let mut chunk = Vec::with_capacity(self.chunk_len);
+                    chunk.push(item);
+                    self.chunks.push(chunk);
+                }
+            }
+        }
+        /// Ensures the list of chunks can describe `count` additional elements.
+        ///
+        /// Chunks themselves are allocated only as they are needed.
+        pub fn reserve(&mut self, count: usize) {
+            let spare = self.chunks.last().map(|c| c.capacity() - c.len()).unwrap_or(0);
+            if count > spare {
+                // `usize::div_ceil` is newer than the oldest toolchain the crate supports.
+                #[allow(clippy::manual_div_ceil)]
+                self.chunks.reserve((count - spare + self.chunk_len - 1) / self.chunk_len);
+            }
+        }
+        /// The number of elements.
+        #[inline]
+        pub fn len(&self) -> usize {
+            match self.chunks.last() {
+                Some(chunk) => (self.chunks.len() - 1) * self.chunk_len + chunk.len(),
+                None => 0,
+            }
+        }
+        /// Returns `true` if there are no elements.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+        /// Returns the element at `index`, or `None` if out of bounds.
+        #[inline]
+        pub fn get(&self, index: usize) -> Option<&T> {
+            self.chunks.get(index / self.chunk_len)?.get(index % self.chunk_len)
+        }
+        /// The elements as a sequence of contiguous slices, in order.
+        pub fn as_slices(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+            self.chunks.iter().map(|chunk| &chunk[..])
+        }
+        /// Iterates over the elements, in order.
+        pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+            self.chunks.iter().flat_map(|chunk| chunk.iter())
+        }
+        /// Discards all elements without dropping them.
+        ///
+        /// The allocation of the first chunk is retained.
+        ///
+        /// # Safety
+        ///
+        /// The elements' `Drop` implementations are not run.
+        pub(crate) unsafe fn forget(&mut self) {
+            for chunk in self.chunks.iter_mut() {
+                chunk.set_len(0);
+            }
+            self.chunks.truncate(1);
+        }
+        /// Estimate the memory capacity in bytes.
+        #[inline]
+        pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+            let size_of_t = std::mem::size_of::<T>();
+            let size_of_chunk = std::mem::size_of::<Vec<T>>();
+            callback(self.chunks.len() * size_of_chunk, self.chunks.capacity() * size_of_chunk);
+            for chunk in &self.chunks {
+                callback(chunk.len() * size_of_t, chunk.capacity() * size_of_t);
+            }
+        }
+    }
+
+    impl<T> std::ops::Index<usize> for ChunkedVec<T> {
+        type Output = T;
+        #[inline(always)]
+        fn index(&self, index: usize) -> &Self::Output {
+            &self.chunks[index / self.chunk_len][index % self.chunk_len]
+        }
+    }
+
+    /// An append-only vector that store records as columns, with a chunked spine.
+    ///
+    /// This container parallels [ColumnStack](crate::ColumnStack), but stores its records
+    /// in a [ChunkedVec] rather than a `Vec`. Growing the container never reallocates
+    /// existing records, which avoids copying the spine and briefly doubling its memory
+    /// when it is very large. Records are not contiguous, and are accessed by indexing,
+    /// by iteration, or as contiguous chunks with `as_slices`.
+    pub struct ChunkedColumnStack<T: Columnation> {
+        pub(crate) local: ChunkedVec<T>,
+        pub(crate) inner: T::InnerRegion,
+    }
+
+    impl<T: Columnation> ChunkedColumnStack<T> {
+        /// Construct a [ChunkedColumnStack] whose spine chunks hold `chunk_len` records.
+        pub fn with_chunk_len(chunk_len: usize) -> Self {
+            Self {
+                local: ChunkedVec::with_chunk_len(chunk_len),
+                inner: T::InnerRegion::default(),
+            }
+        }
+
+        /// Ensures `Self` can absorb `items` without further allocations of the region.
+        ///
+        /// The argument `items` may be cloned and iterated multiple times.
+        /// Please be careful if it contains side effects.
+        #[inline(always)]
+        pub fn reserve_items<'a, I>(&mut self, items: I)
+        where
+            Self: 'a,
+            I: Iterator<Item= &'a T>+Clone,
+        {
+            self.local.reserve(items.clone().count());
+            self.inner.reserve_items(items);
+        }
+
+        /// Copies an element in to the region.
+        ///
+        /// The element can be read by indexing
+        pub fn copy(&mut self, item: &T) {
+            unsafe {
+                self.local.push(self.inner.copy(item));
+            }
+        }
+        /// Installs `hook` in the region, to observe its allocations, or removes it if `None`.
+        #[cfg(feature = "hooks")]
+        pub fn set_hook(&mut self, hook: Option<RegionHook>) {
+            self.inner.set_hook(hook.as_ref());
+        }
+        /// Empties the collection.
+        pub fn clear(&mut self) {
+            unsafe {
+                // Unsafety justified in that the records do not own their resources.
+                self.local.forget();
+                self.inner.clear();
+            }
+        }
+        /// The number of records.
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.local.len()
+        }
+        /// Returns `true` if there are no records.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.local.is_empty()
+        }
+        /// Returns the record at `index`, or `None` if out of bounds.
+        #[inline]
+        pub fn get(&self, index: usize) -> Option<&T> {
+            self.local.get(index)
+        }
+        /// The records as a sequence of contiguous slices, in order.
+        #[inline]
+        pub fn as_slices(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+            self.local.as_slices()
+        }
+        /// Iterates over the records, in order.
+        #[inline]
+        pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+            self.local.iter()
+        }
+
+        /// Estimate the memory capacity in bytes.
+        #[inline]
+        pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+            self.local.heap_size(&mut callback);
+            self.inner.heap_size(callback);
+        }
+
+        /// Estimate the consumed memory capacity in bytes, summing both used and total capacity.
+        #[inline]
+        pub fn summed_heap_size(&self) -> (usize, usize) {
+            let (mut length, mut capacity) = (0, 0);
+            self.heap_size(|len, cap| {
+                length += len;
+                capacity += cap
+            });
+            (length, capacity)
+        }
+    }
+
+    impl<T: Columnation> std::ops::Index<usize> for ChunkedColumnStack<T> {
+        type Output = T;
+        #[inline(always)]
+        fn index(&self, index: usize) -> &Self::Output {
+            &self.local[index]
+        }
+    }
+
+    impl<T: Columnation> Drop for ChunkedColumnStack<T> {
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+
+    impl<T: Columnation> Default for ChunkedColumnStack<T> {
+        fn default() -> Self {
+            Self {
+                local: ChunkedVec::default(),
+                inner: T::InnerRegion::default(),
+            }
+        }
+    }
+
+    impl<'a, T: Columnation + 'a> Extend<&'a T> for ChunkedColumnStack<T> {
+        fn extend<I: IntoIterator<Item=&'a T>>(&mut self, iter: I) {
+            for element in iter {
+                self.copy(element)
+            }
+        }
+    }
+
+    impl<'a, T: Columnation + 'a> std::iter::FromIterator<&'a T> for ChunkedColumnStack<T> {
+        fn from_iter<I: IntoIterator<Item=&'a T>>(iter: I) -> Self {
+            let mut c = Self::default();
+            c.extend(iter);
+            c
+        }
+    }
+
+    impl<T: Columnation + PartialEq> PartialEq for ChunkedColumnStack<T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.len() == other.len() && self.iter().eq(other.iter())
+        }
+    }
+
+    impl<T: Columnation + Eq> Eq for ChunkedColumnStack<T> {}
+
+    impl<T: Columnation + std::fmt::Debug> std::fmt::Debug for ChunkedColumnStack<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+
+    impl<T: Columnation> Clone for ChunkedColumnStack<T> {
+        fn clone(&self) -> Self {
+            let mut new = Self::with_chunk_len(self.local.chunk_len());
+            for item in self.iter() {
+                new.copy(item);
+            }
+            new
+        }
+    }
+}
+
+pub use columnqueue::ColumnQueue;
+
+mod columnqueue {
+
+    use std::collections::VecDeque;
+
+    use super::{Columnation, ColumnStack};
+    #[cfg(feature = "hooks")]
+    use super::RegionHook;
+
+    /// A first-in first-out queue that store records as columns.
+    ///
+    /// Records are pushed in to segments, each a [ColumnStack] with its own region.
+    /// A segment is closed once it holds `segment_len` records, and its region memory
+    /// is recycled once every one of its records has been popped. At most one empty
+    /// segment is retained for reuse; others are released to the allocator.
+    pub struct ColumnQueue<T: Columnation> {
+        /// Segments of records, from oldest to newest.
+        segments: VecDeque<ColumnStack<T>>,
+        /// The number of records popped from the front segment.
+        head: usize,
+        /// The number of records after which a segment is closed.
+        segment_len: usize,
+        /// An empty segment retained for reuse.
+        spare: Option<ColumnStack<T>>,
+        /// Hook installed in the region of each segment.
+        #[cfg(feature = "hooks")]
+        hook: Option<RegionHook>,
+    }
+
+    impl<T: Columnation> ColumnQueue<T> {
+        /// Construct a [ColumnQueue] whose segments hold `segment_len` records.
+        pub fn with_segment_len(segment_len: usize) -> Self {
+            assert!(segment_len > 0, "segments must hold at least one record");
+            Self {
+                segments: VecDeque::new(),
+                head: 0,
+                segment_len,
+                spare: None,
+                #[cfg(feature = "hooks")]
+                hook: None,
+            }
+        }
+
+        /// Copies an element in to the back of the queue.
+        pub fn copy(&mut self, item: &T) {
+            self.reclaim();
+            let full = self.segments.back().map(|s| s.len() >= self.segment_len).unwrap_or(true);
+            if full {
+                let mut segment = self.spare.take().unwrap_or_default();
+                #[cfg(feature = "hooks")]
+                segment.set_hook(self.hook.clone());
+                segment.local.reserve(self.segment_len);
+                self.segments.push_back(segment);
+            }
+            self.segments.back_mut().unwrap().copy(item);
+        }
+        /// The record at the front of the queue, if any.
+        #[inline]
+        pub fn front(&self) -> Option<&T> {
+            let front = self.segments.front()?;
+            front.get(self.head).or_else(|| self.segments.get(1).and_then(|s| s.first()))
+        }
+        /// Removes the record at the front of the queue, and returns a reference to it.
+        ///
+        /// The record remains valid until the queue is next mutated, at which point
+        /// the memory of exhausted segments is reclaimed.
+        pub fn pop(&mut self) -> Option<&T> {
+            self.reclaim();
+            let front = self.segments.front()?;
+            if self.head < front.len() {
+                self.head += 1;
+                Some(&front[self.head - 1])
+            } else {
+                None
+            }
+        }
+        /// Installs `hook` in the region of each segment, present and future, or removes it if `None`.
+        #[cfg(feature = "hooks")]
+        pub fn set_hook(&mut self, hook: Option<RegionHook>) {
+            for segment in self.segments.iter_mut() {
+                segment.set_hook(hook.clone());
+            }
+            self.hook = hook;
+        }
+        /// Empties the queue.
+        pub fn clear(&mut self) {
+            while let Some(mut segment) = self.segments.pop_front() {
+                segment.clear();
+                self.spare.get_or_insert(segment);
+            }
+            self.head = 0;
+        }
+
+        /// The number of records in the queue.
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.segments.iter().map(|s| s.len()).sum::<usize>() - self.head
+        }
+        /// Returns `true` if the queue holds no records.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+        /// Iterates over the records in the queue, from front to back.
+        pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+            let head = self.head;
+            self.segments.iter().enumerate().flat_map(move |(index, segment)| {
+                segment[if index == 0 { head } else { 0 } ..].iter()
+            })
+        }
+
+        /// Estimate the memory capacity in bytes.
+        ///
+        /// This includes records that have been popped but not yet reclaimed.
+        #[inline]
+        pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+            let size_of_segment = std::mem::size_of::<ColumnStack<T>>();
+            callback(self.segments.len() * size_of_segment, self.segments.capacity() * size_of_segment);
+            for segment in self.segments.iter().chain(self.spare.iter()) {
+                segment.heap_size(&mut callback);
+            }
+        }
+
+        /// Estimate the consumed memory capacity in bytes, summing both used and total capacity.
+        #[inline]
+        pub fn summed_heap_size(&self) -> (usize, usize) {
+            let (mut length, mut capacity) = (0, 0);
+            self.heap_size(|len, cap| {
+                length += len;
+                capacity += cap
+            });
+            (length, capacity)
+        }
+
+        /// Recycles the front segment if all of its records have been popped.
+        ///
+        /// At most one segment can be exhausted, as `pop` returns records from the
+        /// front segment only after reclaiming exhausted segments.
+        fn reclaim(&mut self) {
+            let exhausted = self.segments.front().map(|s| self.head >= s.len()).unwrap_or(false);
+            if exhausted {
+                if self.segments.len() == 1 {
+                    // The only segment can be cleared in place, and remains open to new records.
+                    self.segments[0].clear();
+                } else {
+                    let mut segment = self.segments.pop_front().unwrap();
+                    segment.clear();
+                    self.spare.get_or_insert(segment);
+                }
+                self.head = 0;
+            }
+        }
+    }
+
+    impl<T: Columnation> Default for ColumnQueue<T> {
+        fn default() -> Self {
+            // Segments of roughly 64KiB of records, and at least one record.
+            let size_of_t = std::cmp::max(std::mem::size_of::<T>(), 1);
+            Self::with_segment_len(std::cmp::max((1 << 16) / size_of_t, 1))
+        }
+    }
+
+    impl<'a, T: Columnation + 'a> Extend<&'a T> for ColumnQueue<T> {
+        fn extend<I: IntoIterator<Item=&'a T>>(&mut self, iter: I) {
+            for element in iter {
+                self.copy(element)
+            }
+        }
+    }
+
+    impl<T: Columnation + std::fmt::Debug> std::fmt::Debug for ColumnQueue<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+}
+
+pub use columnslab::ColumnSlab;
+
+mod columnslab {
+
+    use super::{Columnation, Region};
+    #[cfg(feature = "hooks")]
+    use super::RegionHook;
+
+    /// A slab of records stored as columns, with stable ids and removal.
+    ///
+    /// Records are inserted in to slots of a spine, and identified by their slot
+    /// index. Removing a record marks its slot as vacant, for reuse by a later
+    /// insertion, and its region memory as dead. Dead memory is reclaimed by
+    /// compacting the region, which copies live records in to a fresh region and
+    /// leaves their ids unchanged. Compaction happens on request, and automatically
+    /// once dead memory exceeds a fraction of region memory, if one is set with
+    /// [set_compact_fraction](Self::set_compact_fraction).
+    pub struct ColumnSlab<T: Columnation> {
+        /// Slots for records, some of which may be vacant.
+        local: Vec<T>,
+        /// Region for allocations of records in `local`.
+        inner: T::InnerRegion,
+        /// A bitmap of slots that hold a live record.
+        occupied: Vec<u64>,
+        /// Vacant slots, for reuse.
+        vacant: Vec<usize>,
+        /// Region bytes used by the record in each slot.
+        sizes: Vec<usize>,
+        /// Region bytes used by live records.
+        live_bytes: usize,
+        /// Region bytes used by removed records.
+        dead_bytes: usize,
+        /// Fraction of region bytes that may be dead before compaction, if any.
+        compact_fraction: Option<f64>,
+        /// Hook installed in the region, and in regions created by compaction.
+        #[cfg(feature = "hooks")]
+        hook: Option<RegionHook>,
+    }
+
+    impl<T: Columnation> ColumnSlab<T> {
+        /// Sets the fraction of dead region bytes that triggers compaction.
+        ///
+        /// A value of `None`, the default, disables automatic compaction.
+        pub fn set_compact_fraction(&mut self, fraction: Option<f64>) {
+            self.compact_fraction = fraction;
+        }
+        /// Installs `hook` in the region, to observe its allocations, or removes it if `None`.
+        ///
+        /// The hook remains installed across compaction.
+        #[cfg(feature = "hooks")]
+        pub fn set_hook(&mut self, hook: Option<RegionHook>) {
+            self.inner.set_hook(hook.as_ref());
+            self.hook = hook;
+        }
+
+        /// Copies a record in to the slab, and returns its id.
+        ///
+        /// The id remains valid until the record is removed.
+        pub fn insert(&mut self, item: &T) -> usize {
+            let size = Self::record_bytes(item);
+            let copy = unsafe { self.inner.copy(item) };
+            self.live_bytes += size;
+            let id = match self.vacant.pop() {
+                Some(id) => {
+                    // Unsafety justified in that the vacant slot holds a removed record,
+                    // which does not own its resources and must not be dropped.
+                    unsafe { std::ptr::write(&mut self.local[id], copy); }
+                    self.sizes[id] = size;
+                    id
+                },
+                None => {
+                    self.local.push(copy);
+                    self.sizes.push(size);
+                    if self.occupied.len() * 64 < self.local.len() {
+                        self.occupied.push(0);
+                    }
+                    self.local.len() - 1
+                },
+            };
+            self.occupied[id / 64] |= 1 << (id % 64);
+            id
+        }
+        /// Removes the record with id `id`, returning `true` if it was present.
+        pub fn remove(&mut self, id: usize) -> bool {
+            if !self.contains(id) {
+                return false;
+            }
+            self.occupied[id / 64] &= !(1 << (id % 64));
+            self.vacant.push(id);
+            self.live_bytes -= self.sizes[id];
+            self.dead_bytes += self.sizes[id];
+            if let Some(fraction) = self.compact_fraction {
+                let total = self.live_bytes + self.dead_bytes;
+                if self.dead_bytes > 0 && self.dead_bytes as f64 > fraction * total as f64 {
+                    self.compact();
+                }
+            }
+            true
+        }
+        /// Returns `true` if the slab holds a record with id `id`.
+        #[inline]
+        pub fn contains(&self, id: usize) -> bool {
+            id < self.local.len() && self.occupied[id / 64] & (1 << (id % 64)) != 0
+        }
+        /// Returns the record with id `id`, if present.
+        #[inline]
+        pub fn get(&self, id: usize) -> Option<&T> {
+            if self.contains(id) { Some(&self.local[id]) } else { None }
+        }
+        /// Iterates over the ids and records in the slab, in order of id.
+        pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> + Clone + '_ {
+            (0 .. self.local.len()).filter(move |id| self.contains(*id)).map(move |id| (id, &self.local[id]))
+        }
+        /// The number of records in the slab.
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.local.len() - self.vacant.len()
+        }
+        /// Returns `true` if the slab holds no records.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+        /// Region bytes used by live records.
+        ///
+        /// Regions whose items share memory, as reported by [Region::SHARES_MEMORY], cannot
+        /// attribute bytes to records, and report no live or dead bytes. Such slabs are only
+        /// compacted on request.
+        #[inline]
+        pub fn live_bytes(&self) -> usize {
+            self.live_bytes
+        }
+        /// Region bytes used by removed records, which compaction would reclaim.
+        #[inline]
+        pub fn dead_bytes(&self) -> usize {
+            self.dead_bytes
+        }
+        /// The region bytes attributed to `item`, which are zero for regions that share memory.
+        #[inline]
+        fn record_bytes(item: &T) -> usize {
+            if T::InnerRegion::SHARES_MEMORY {
+                0
+            } else {
+                T::InnerRegion::item_heap_size(item)
+            }
+        }
+
+        /// Copies live records in to a fresh region, and releases the current region.
+        ///
+        /// Record ids are unchanged.
+        pub fn compact(&mut self) {
+            let mut region = T::InnerRegion::default();
+            #[cfg(feature = "hooks")]
+            region.set_hook(self.hook.as_ref());
+            region.reserve_items(self.iter().map(|(_, item)| item));
+            self.live_bytes = 0;
+            for id in 0 .. self.local.len() {
+                if self.contains(id) {
+                    unsafe {
+                        // Unsafety justified in that the prior record does not own its
+                        // resources, and is discarded with the prior region.
+                        let copy = region.copy(&self.local[id]);
+                        std::ptr::write(&mut self.local[id], copy);
+                    }
+                    self.sizes[id] = Self::record_bytes(&self.local[id]);
+                    self.live_bytes += self.sizes[id];
+                }
+            }
+            std::mem::swap(&mut self.inner, &mut region);
+            region.clear();
+            self.dead_bytes = 0;
+        }
+        /// Empties the slab.
+        pub fn clear(&mut self) {
+            unsafe {
+                // Unsafety justified in that setting the length to zero exposes
+                // no invalid data.
+                self.local.set_len(0);
+            }
+            self.inner.clear();
+            self.occupied.clear();
+            self.vacant.clear();
+            self.sizes.clear();
+            self.live_bytes = 0;
+            self.dead_bytes = 0;
+        }
+
+        /// Estimate the memory capacity in bytes.
+        #[inline]
+        pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+            let size_of_t = std::mem::size_of::<T>();
+            callback(self.local.len() * size_of_t, self.local.capacity() * size_of_t);
+            let size_of_u64 = std::mem::size_of::<u64>();
+            callback(self.occupied.len() * size_of_u64, self.occupied.capacity() * size_of_u64);
+            let size_of_usize = std::mem::size_of::<usize>();
+            callback(self.vacant.len() * size_of_usize, self.vacant.capacity() * size_of_usize);
+            callback(self.sizes.len() * size_of_usize, self.sizes.capacity() * size_of_usize);
+            self.inner.heap_size(callback);
+        }
+
+        /// Estimate the consumed memory capacity in bytes, summing both used and total capacity.
+        #[inline]
+        pub fn summed_heap_size(&self) -> (usize, usize) {
+            let (mut length, mut capacity) = (0, 0);
+            self.heap_size(|len, cap| {
+                length += len;
+                capacity += cap
+            });
+            (length, capacity)
+        }
+    }
+
+    impl<T: Columnation> std::ops::Index<usize> for ColumnSlab<T> {
+        type Output = T;
+        #[inline]
+        fn index(&self, id: usize) -> &Self::Output {
+            self.get(id).expect("no record with this id")
+        }
+    }
+
+    impl<T: Columnation> Drop for ColumnSlab<T> {
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+
+    impl<T: Columnation> Default for ColumnSlab<T> {
+        fn default() -> Self {
+            Self {
+                local: Vec::new(),
+                inner: T::InnerRegion::default(),
+                occupied: Vec::new(),
+                vacant: Vec::new(),
+                sizes: Vec::new(),
+                live_bytes: 0,
+                dead_bytes: 0,
+                compact_fraction: None,
+                #[cfg(feature = "hooks")]
+                hook: None,
+            }
+        }
+    }
+
+    impl<T: Columnation + std::fmt::Debug> std::fmt::Debug for ColumnSlab<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_map().entries(self.iter()).finish()
+        }
+    }
+}
+
+pub use columnbuilder::ColumnStackBuilder;
+
+mod columnbuilder {
+
+    use std::collections::VecDeque;
+
+    use super::{Columnation, ColumnStack};
+    #[cfg(feature = "hooks")]
+    use super::RegionHook;
+
+    /// Builds batches of records as [ColumnStack]s of bounded size.
+    ///
+    /// Records are copied in to a current stack, which is emitted once it holds at least
+    /// `target_bytes` bytes or `target_records` records. The bytes of a stack are those of
+    /// its spine, its recorded region positions and its region, tracked incrementally as
+    /// records are copied. Emitted stacks can be handed back with [recycle](Self::recycle),
+    /// and are reused for later batches so that a steady stream of batches does not allocate.
+    pub struct ColumnStackBuilder<T: Columnation> {
+        /// The stack records are copied in to.
+        current: ColumnStack<T>,
+        /// The bytes of `current`.
+        current_bytes: usize,
+        /// The number of bytes after which a stack is emitted.
+        target_bytes: usize,
+        /// The number of records after which a stack is emitted.
+        target_records: usize,
+        /// Emitted stacks, from oldest to newest.
+        ready: VecDeque<ColumnStack<T>>,
+        /// Empty stacks retained for reuse.
+        spare: Vec<ColumnStack<T>>,
+        /// Hook installed in the region of each stack.
+        #[cfg(feature = "hooks")]
+        hook: Option<RegionHook>,
+    }
+
+    impl<T: Columnation> ColumnStackBuilder<T> {
+        /// Construct a [ColumnStackBuilder] that emits stacks of `target_bytes` bytes or
+        /// `target_records` records, whichever is reached first.
+        pub fn with_targets(target_bytes: usize, target_records: usize) -> Self {
+            assert!(target_records > 0, "batches must hold at least one record");
+            Self {
+                current: ColumnStack::default(),
+                current_bytes: 0,
+                target_bytes,
+                target_records,
+                ready: VecDeque::new(),
+                spare: Vec::new(),
+                #[cfg(feature = "hooks")]
+                hook: None,
+            }
+        }
+
+        /// Copies an element in to the current stack, emitting it if it is full.
+        pub fn copy(&mut self, item: &T) {
+            if self.current.is_empty() {
+                if let Some(spare) = self.spare.pop() {
+                    self.current = spare;
+                }
+                #[cfg(feature = "hooks")]
+                self.current.set_hook(self.hook.clone());
+            }
+            self.current_bytes += self.current.element_bytes(item);
+            self.current.copy(item);
+            if self.current_bytes >= self.target_bytes || self.current.len() >= self.target_records {
+                self.emit();
+            }
+        }
+        /// Emits the current stack, and starts a new one.
+        ///
+        /// The new stack does not allocate; it is replaced by a spare stack, if any, on the next copy.
+        fn emit(&mut self) {
+            self.ready.push_back(std::mem::take(&mut self.current));
+            self.current_bytes = 0;
+        }
+        /// Installs `hook` in the region of the current stack and of later stacks, or removes it if `None`.
+        ///
+        /// Stacks already emitted are not affected.
+        #[cfg(feature = "hooks")]
+        pub fn set_hook(&mut self, hook: Option<RegionHook>) {
+            self.current.set_hook(hook.clone());
+            self.hook = hook;
+        }
+        /// Removes the oldest emitted stack, if any.
+        pub fn extract(&mut self) -> Option<ColumnStack<T>> {
+            self.ready.pop_front()
+        }
+        /// Emits the current stack if it is not empty, and removes the oldest emitted stack, if any.
+        ///
+        /// Call repeatedly until it returns `None` to drain the builder.
+        pub fn finish(&mut self) -> Option<ColumnStack<T>> {
+            if !self.current.is_empty() {
+                self.emit();
+            }
+            self.extract()
+        }
+        /// Hands back an emitted stack, whose allocations are reused for later batches.
+        pub fn recycle(&mut self, mut stack: ColumnStack<T>) {
+            stack.clear_retaining();
+            self.spare.push(stack);
+        }
+        /// The number of records in the current stack.
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.current.len()
+        }
+        /// Returns `true` if the current stack holds no records.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.current.is_empty()
+        }
+        /// The bytes of the current stack.
+        #[inline]
+        pub fn bytes(&self) -> usize {
+            self.current_bytes
+        }
+    }
+
+    impl<T: Columnation> Default for ColumnStackBuilder<T> {
+        /// A builder that emits stacks of about one megabyte.
+        fn default() -> Self {
+            Self::with_targets(1 << 20, usize::MAX)
+        }
+    }
+}
+
+pub use heapreport::{HeapEntry, HeapReport};
+
+mod heapreport {
+
+    use std::collections::BTreeMap;
+
+    use super::Region;
+
+    /// Memory used and reserved by the allocations at one path of a [HeapReport].
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct HeapEntry {
+        /// Bytes in use.
+        pub used: usize,
+        /// Bytes allocated.
+        pub reserved: usize,
+        /// The number of allocations, not counting those with zero capacity.
+        pub allocations: usize,
+        /// A histogram of allocation sizes: entry `i` counts allocations of at least
+        /// `2^i` bytes and fewer than `2^(i+1)` bytes.
+        pub histogram: Vec<usize>,
+    }
+
+    impl HeapEntry {
+        /// Records an allocation of `reserved` bytes, of which `used` are in use.
+        pub fn record(&mut self, used: usize, reserved: usize) {
+            self.used += used;
+            self.reserved += reserved;
+            if reserved > 0 {
+                self.allocations += 1;
+                // `usize::ilog2` is newer than the oldest toolchain the crate supports.
+                let bucket = (usize::BITS - 1 - reserved.leading_zeros()) as usize;
+                if self.histogram.len() <= bucket {
+                    self.histogram.resize(bucket + 1, 0);
+                }
+                self.histogram[bucket] += 1;
+            }
+        }
+        /// Adds the allocations of `other` to `self`.
+        pub fn merge(&mut self, other: &Self) {
+            self.used += other.used;
+            self.reserved += other.reserved;
+            self.allocations += other.allocations;
+            if self.histogram.len() < other.histogram.len() {
+                self.histogram.resize(other.histogram.len(), 0);
+            }
+            for (count, other) in self.histogram.iter_mut().zip(other.histogram.iter()) {
+                *count += other;
+            }
+        }
+    }
+
+    /// Memory used and reserved by a region, aggregated by path.
+    ///
+    /// Paths are those of [Region::heap_size_paths], and attribute allocations to the
+    /// components and nesting levels of the region's items, for example `.2.vec.elements`
+    /// for the elements of vectors in the third component of tuples.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct HeapReport {
+        entries: BTreeMap<String, HeapEntry>,
+    }
+
+    impl HeapReport {
+        /// A report of the allocations of `region`.
+        pub fn from_region<R: Region>(region: &R) -> Self {
+            let mut report = Self::default();
+            report.record_region(region, "");
+            report
+        }
+        /// Records the allocations of `region`, with paths starting at `path`.
+        pub fn record_region<R: Region>(&mut self, region: &R, path: &str) {
+            region.heap_size_paths(&mut path.to_string(), |path, used, reserved| self.record(path, used, reserved));
+        }
+        /// Records an allocation at `path` of `reserved` bytes, of which `used` are in use.
+        pub fn record(&mut self, path: &str, used: usize, reserved: usize) {
+            match self.entries.get_mut(path) {
+                Some(entry) => entry.record(used, reserved),
+                None => self.entries.entry(path.to_string()).or_default().record(used, reserved),
+            }
+        }
+        /// The allocations at `path`, if any.
+        pub fn get(&self, path: &str) -> Option<&HeapEntry> {
+            self.entries.get(path)
+        }
+        /// The paths and their allocations, in order of path.
+        pub fn iter(&self) -> impl Iterator<Item = (&str, &HeapEntry)> {
+            self.entries.iter().map(|(path, entry)| (path.as_str(), entry))
+        }
+        /// The allocations at all paths.
+        pub fn total(&self) -> HeapEntry {
+            let mut total = HeapEntry::default();
+            for entry in self.entries.values() {
+                total.merge(entry);
+            }
+            total
+        }
+    }
+
+    impl std::fmt::Display for HeapReport {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            for (path, entry) in self.iter() {
+                writeln!(f, "{}: {} used, {} reserved, {} allocations", path, entry.used, entry.reserved, entry.allocations)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+pub use slicestack::{SliceStack, StrStack};
+pub use implementations::tuple::{ColumnIndex, TupleColumnation, TupleColumns, TupleIndex};
+
+mod slicestack {
+
+    use super::StableRegion;
+
+    /// An append-only vector of slices, stored contiguously.
+    ///
+    /// This container parallels [ColumnStack](crate::ColumnStack) for records that are
+    /// slices `[T]`, but rather than falsify a `Vec<T>` per record it copies the elements
+    /// of each record in to one allocation of a [StableRegion], and maintains the end offset
+    /// of each record, one word per record. Records are retrieved by index as `&[T]`.
+    pub struct SliceStack<T> {
+        /// The end offset of each record, counting the elements of all allocations in order.
+        ends: Vec<usize>,
+        /// The offset of the first element of each allocation in `elements`.
+        chunk_starts: Vec<usize>,
+        /// The elements of all records, which the stack owns.
+        elements: StableRegion<T>,
+    }
+
+    impl<T> SliceStack<T> {
+        /// Ensures `Self` can absorb `items` without further allocations.
+        ///
+        /// The argument `items` may be cloned and iterated multiple times.
+        /// Please be careful if it contains side effects.
+        #[inline(always)]
+        pub fn reserve_items<'a, I>(&mut self, items: I)
+        where
+            T: 'a,
+            I: Iterator<Item = &'a [T]> + Clone,
+        {
+            self.ends.reserve(items.clone().count());
+            self.elements.reserve(items.map(|x| x.len()).sum());
+        }
+
+        /// Ensures `Self` can absorb the records of `stacks` without further allocations.
+        #[inline(always)]
+        pub fn reserve_regions<'a, I>(&mut self, stacks: I)
+        where
+            T: 'a,
+            I: Iterator<Item = &'a Self> + Clone,
+        {
+            self.ends.reserve(stacks.clone().map(|s| s.ends.len()).sum());
+            self.elements.reserve(stacks.map(|s| s.elements.len()).sum());
+        }
+
+        /// Copies a slice in to the stack.
+        ///
+        /// The slice can be read by indexing.
+        #[inline]
+        pub fn copy(&mut self, item: &[T])
+        where
+            T: Clone,
+        {
+            // The region copies each slice in to a single allocation, the active one.
+            let start = self.ends.last().copied().unwrap_or(0);
+            self.elements.copy_slice(item);
+            if self.chunk_starts.len() <= self.elements.stashed_chunks() {
+                self.chunk_starts.push(start);
+            }
+            self.ends.push(start + item.len());
+        }
+        /// Empties the collection.
+        pub fn clear(&mut self) {
+            self.ends.clear();
+            self.chunk_starts.clear();
+            self.elements.drop_items();
+            self.elements.clear();
+        }
+
+        /// The number of records in the stack.
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.ends.len()
+        }
+        /// Returns `true` if the stack holds no records.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.ends.is_empty()
+        }
+        /// Returns the record at `index`, or `None` if out of bounds.
+        #[inline]
+        pub fn get(&self, index: usize) -> Option<&[T]> {
+            if index < self.ends.len() {
+                Some(&self[index])
+            } else {
+                None
+            }
+        }
+        /// Iterates over the records in the stack.
+        pub fn iter(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+            (0 .. self.len()).map(move |index| &self[index])
+        }
+
+        /// Estimate the memory capacity in bytes.
+        #[inline]
+        pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+            let size_of_offset = std::mem::size_of::<usize>();
+            callback(self.ends.len() * size_of_offset, self.ends.capacity() * size_of_offset);
+            callback(self.chunk_starts.len() * size_of_offset, self.chunk_starts.capacity() * size_of_offset);
+            self.elements.heap_size(callback);
+        }
+
+        /// Estimate the consumed memory capacity in bytes, summing both used and total capacity.
+        #[inline]
+        pub fn summed_heap_size(&self) -> (usize, usize) {
+            let (mut length, mut capacity) = (0, 0);
+            self.heap_size(|len, cap| {
+                length += len;
+                capacity += cap
+            });
+            (length, capacity)
+        }
+    }
+
+    impl<T> std::ops::Index<usize> for SliceStack<T> {
+        type Output = [T];
+        #[inline(always)]
+        fn index(&self, index: usize) -> &Self::Output {
+            let upper = self.ends[index];
+            let lower = index.checked_sub(1).map_or(0, |prev| self.ends[prev]);
+            // Each record lies within the last allocation that starts at or before it.
+            let chunk = self.chunk_starts.partition_point(|start| *start <= lower) - 1;
+            let offset = self.chunk_starts[chunk];
+            &self.elements.chunk(chunk)[lower - offset .. upper - offset]
+        }
+    }
+
+    impl<T> Default for SliceStack<T> {
+        fn default() -> Self {
+            Self {
+                ends: Vec::new(),
+                chunk_starts: Vec::new(),
+                elements: StableRegion::default(),
+            }
+        }
+    }
+
+    impl<'a, T: Clone + 'a> Extend<&'a [T]> for SliceStack<T> {
+        fn extend<I: IntoIterator<Item=&'a [T]>>(&mut self, iter: I) {
+            for element in iter {
+                self.copy(element)
+            }
+        }
+    }
+
+    impl<'a, T: Clone + 'a> std::iter::FromIterator<&'a [T]> for SliceStack<T> {
+        fn from_iter<I: IntoIterator<Item=&'a [T]>>(iter: I) -> Self {
+            let mut c = Self::default();
+            c.extend(iter);
+            c
+        }
+    }
+
+    impl<T: PartialEq> PartialEq for SliceStack<T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.iter().eq(other.iter())
+        }
+    }
+
+    impl<T: Eq> Eq for SliceStack<T> {}
+
+    impl<T: std::fmt::Debug> std::fmt::Debug for SliceStack<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+
+    impl<T: Clone> Clone for SliceStack<T> {
+        fn clone(&self) -> Self {
+            let mut clone = Self::default();
+            clone.reserve_regions(std::iter::once(self));
+            clone.extend(self.iter());
+            clone
+        }
+    }
+
+    /// An append-only vector of strings, stored contiguously.
+    ///
+    /// This wraps a [SliceStack] of bytes, and retrieves records by index as `&str`.
+    #[derive(Clone, Default, PartialEq, Eq)]
+    pub struct StrStack {
+        bytes: SliceStack<u8>,
+    }
+
+    impl StrStack {
+        /// Ensures `Self` can absorb `items` without further allocations.
+        #[inline(always)]
+        pub fn reserve_items<'a, I>(&mut self, items: I)
+        where
+            I: Iterator<Item = &'a str> + Clone,
+        {
+            self.bytes.reserve_items(items.map(|x| x.as_bytes()));
+        }
+        /// Ensures `Self` can absorb the records of `stacks` without further allocations.
+        #[inline(always)]
+        pub fn reserve_regions<'a, I>(&mut self, stacks: I)
+        where
+            I: Iterator<Item = &'a Self> + Clone,
+        {
+            self.bytes.reserve_regions(stacks.map(|s| &s.bytes));
+        }
+        /// Copies a string in to the stack.
+        ///
+        /// The string can be read by indexing.
+        #[inline]
+        pub fn copy(&mut self, item: &str) {
+            self.bytes.copy(item.as_bytes());
+        }
+        /// Empties the collection.
+        pub fn clear(&mut self) {
+            self.bytes.clear();
+        }
+        /// The number of records in the stack.
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.bytes.len()
+        }
+        /// Returns `true` if the stack holds no records.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.bytes.is_empty()
+        }
+        /// Returns the record at `index`, or `None` if out of bounds.
+        #[inline]
+        pub fn get(&self, index: usize) -> Option<&str> {
+            // Unsafety justified in that records are only ever copied from `str` data.
+            self.bytes.get(index).map(|bytes| unsafe { std::str::from_utf8_unchecked(bytes) })
+        }
+        /// Iterates over the records in the stack.
+        pub fn iter(&self) -> impl ExactSizeIterator<Item = &str> + '_ {
+            (0 .. self.len()).map(move |index| &self[index])
+        }
+        /// Estimate the memory capacity in bytes.
+        #[inline]
+        pub fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+            self.bytes.heap_size(callback)
+        }
+        /// Estimate the consumed memory capacity in bytes, summing both used and total capacity.
+        #[inline]
+        pub fn summed_heap_size(&self) -> (usize, usize) {
+            self.bytes.summed_heap_size()
+        }
+    }
+
+    impl std::ops::Index<usize> for StrStack {
+        type Output = str;
+        #[inline(always)]
+        fn index(&self, index: usize) -> &Self::Output {
+            // Unsafety justified in that records are only ever copied from `str` data.
+            unsafe { std::str::from_utf8_unchecked(&self.bytes[index]) }
+        }
+    }
+
+    impl<'a> Extend<&'a str> for StrStack {
+        fn extend<I: IntoIterator<Item=&'a str>>(&mut self, iter: I) {
+            for element in iter {
+                self.copy(element)
+            }
+        }
+    }
+
+    impl<'a> std::iter::FromIterator<&'a str> for StrStack {
+        fn from_iter<I: IntoIterator<Item=&'a str>>(iter: I) -> Self {
+            let mut c = Self::default();
+            c.extend(iter);
+            c
+        }
+    }
+
+    impl std::fmt::Debug for StrStack {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+}
+
+mod implementations {
+
+    use super::{Region, RegionCapacity, CapacityPlan, with_segment, AliasSafe, CopyRegion, CloneRegion, Cloned, InterningRegion, Interned, StableRegion, Columnation, ColumnStack, ChunkedColumnStack, TrivialRegion};
+
+    // Implementations for types whose `clone()` suffices for the region.
+    macro_rules! implement_columnation {
+        ($index_type:ty) => (
+            impl Columnation for $index_type {
+                type InnerRegion = CopyRegion<$index_type>;
+            }
+        )
+    }
+    implement_columnation!(());
+    implement_columnation!(bool);
+    implement_columnation!(char);
+
+    implement_columnation!(u8);
+    implement_columnation!(u16);
+    implement_columnation!(u32);
+    implement_columnation!(u64);
+    implement_columnation!(u128);
+    implement_columnation!(usize);
+
+    implement_columnation!(i8);
+    implement_columnation!(i16);
+    implement_columnation!(i32);
+    implement_columnation!(i64);
+    implement_columnation!(i128);
+    implement_columnation!(isize);
+
+    implement_columnation!(f32);
+    implement_columnation!(f64);
+
+    implement_columnation!(std::num::Wrapping<u8>);
+    implement_columnation!(std::num::Wrapping<u16>);
+    implement_columnation!(std::num::Wrapping<u32>);
+    implement_columnation!(std::num::Wrapping<u64>);
+    implement_columnation!(std::num::Wrapping<u128>);
+    implement_columnation!(std::num::Wrapping<usize>);
+
+    implement_columnation!(std::num::Wrapping<i8>);
+    implement_columnation!(std::num::Wrapping<i16>);
+    implement_columnation!(std::num::Wrapping<i32>);
+    implement_columnation!(std::num::Wrapping<i64>);
+    implement_columnation!(std::num::Wrapping<i128>);
+    implement_columnation!(std::num::Wrapping<isize>);
+
+    /// `Saturating` is stable from Rust 1.74, later than the oldest toolchain the crate
+    /// supports, and so its implementations are behind the `saturating` feature.
+    #[cfg(feature = "saturating")]
+    mod saturating {
+        use super::{Columnation, CopyRegion};
+
+        implement_columnation!(std::num::Saturating<u8>);
+        implement_columnation!(std::num::Saturating<u16>);
+        implement_columnation!(std::num::Saturating<u32>);
+        implement_columnation!(std::num::Saturating<u64>);
+        implement_columnation!(std::num::Saturating<u128>);
+        implement_columnation!(std::num::Saturating<usize>);
+
+        implement_columnation!(std::num::Saturating<i8>);
+        implement_columnation!(std::num::Saturating<i16>);
+        implement_columnation!(std::num::Saturating<i32>);
+        implement_columnation!(std::num::Saturating<i64>);
+        implement_columnation!(std::num::Saturating<i128>);
+        implement_columnation!(std::num::Saturating<isize>);
+    }
+
+    implement_columnation!(std::num::NonZeroU8);
+    implement_columnation!(std::num::NonZeroU16);
+    implement_columnation!(std::num::NonZeroU32);
+    implement_columnation!(std::num::NonZeroU64);
+    implement_columnation!(std::num::NonZeroU128);
+    implement_columnation!(std::num::NonZeroUsize);
+
+    implement_columnation!(std::num::NonZeroI8);
+    implement_columnation!(std::num::NonZeroI16);
+    implement_columnation!(std::num::NonZeroI32);
+    implement_columnation!(std::num::NonZeroI64);
+    implement_columnation!(std::num::NonZeroI128);
+    implement_columnation!(std::num::NonZeroIsize);
+
+    implement_columnation!(std::num::FpCategory);
+    implement_columnation!(std::cmp::Ordering);
+
+    implement_columnation!(std::net::IpAddr);
+    implement_columnation!(std::net::Ipv4Addr);
+    implement_columnation!(std::net::Ipv6Addr);
+    implement_columnation!(std::net::SocketAddr);
+    implement_columnation!(std::net::SocketAddrV4);
+    implement_columnation!(std::net::SocketAddrV6);
+
+    implement_columnation!(std::time::Duration);
+    implement_columnation!(std::time::Instant);
+    implement_columnation!(std::time::SystemTime);
+
+    implement_columnation!(std::any::TypeId);
+
+    impl<T: ?Sized> Columnation for std::marker::PhantomData<T> {
+        type InnerRegion = CopyRegion<std::marker::PhantomData<T>>;
+    }
+
+    /// Implementations for types that are retained as clones.
+    pub mod clone {
+
+        use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        use super::{AliasSafe, Columnation, CloneRegion, Cloned};
+
+        impl<T: Clone + AliasSafe> Columnation for Cloned<T> {
+            type InnerRegion = CloneRegion<Cloned<T>>;
+        }
+
+        impl<T: ?Sized> Columnation for Rc<T> {
+            type InnerRegion = CloneRegion<Rc<T>>;
+        }
+        impl<T: ?Sized> Columnation for Arc<T> {
+            type InnerRegion = CloneRegion<Arc<T>>;
+        }
+
+        impl<K: Clone, V: Clone, S: Clone + AliasSafe> Columnation for HashMap<K, V, S> {
+            type InnerRegion = CloneRegion<HashMap<K, V, S>>;
+        }
+        impl<T: Clone, S: Clone + AliasSafe> Columnation for HashSet<T, S> {
+            type InnerRegion = CloneRegion<HashSet<T, S>>;
+        }
+        impl<K: Clone, V: Clone> Columnation for BTreeMap<K, V> {
+            type InnerRegion = CloneRegion<BTreeMap<K, V>>;
+        }
+        impl<T: Clone> Columnation for BTreeSet<T> {
+            type InnerRegion = CloneRegion<BTreeSet<T>>;
+        }
+    }
+
+    /// Implementations for `Interned<T: Columnation>`.
+    pub mod interned {
+
+        use super::{Columnation, Interned, InterningRegion, Region, RegionCapacity, TrivialRegion};
+
+        /// Region allocation for `Interned<T>`, which delegates to the region of `T`.
+        #[derive(Default)]
+        pub struct InternedRegion<R: Region> {
+            region: R,
+        }
+
+        impl<R: Region> Region for InternedRegion<R> {
+            type Item = Interned<R::Item>;
+            const SHARES_MEMORY: bool = R::SHARES_MEMORY;
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                Interned(self.region.copy(&item.0))
+            }
+            #[inline(always)]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                R::item_heap_size(&item.0)
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve_items(items.map(|x| &x.0));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve_regions(regions.map(|r| &r.region));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                self.region.heap_size_paths(path, callback)
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl<R: RegionCapacity> RegionCapacity for InternedRegion<R> {
+            type Capacity = R::Capacity;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                R::measure_item(&item.0)
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve_capacity(capacity);
+            }
+        }
+
+        unsafe impl<R: TrivialRegion> TrivialRegion for InternedRegion<R> { }
+
+        impl<T: Columnation + std::hash::Hash + Eq> Columnation for Interned<T> {
+            type InnerRegion = InterningRegion<InternedRegion<T::InnerRegion>>;
+        }
+    }
+
+    /// Implementations for wrappers around a single `T: Columnation`.
+    ///
+    /// Each wrapper delegates to the region of `T`, e.g. `ReverseRegion<R>` for `Reverse<T>`.
+    pub mod wrapper {
+
+        use std::cmp::Reverse;
+        use std::mem::ManuallyDrop;
+
+        use super::{Columnation, Region, RegionCapacity, TrivialRegion};
+
+        /// The macro creates a delegating region for a wrapper type.
+        ///
+        /// The `$item => $inner` argument describes how to borrow the wrapped item,
+        /// and `$new` how to wrap a copy of it.
+        macro_rules! wrapper_columnation {
+            ($region:ident, $wrapper:ident, $item:ident => $inner:expr, $new:path) => (
+                #[doc = concat!("Region allocation for `", stringify!($wrapper), "<T>`, which delegates to the region of `T`.")]
+                #[derive(Default)]
+                pub struct $region<R: Region> {
+                    region: R,
+                }
+
+                impl<R: Region> Region for $region<R> {
+                    type Item = $wrapper<R::Item>;
+                    const SHARES_MEMORY: bool = R::SHARES_MEMORY;
+                    #[inline(always)]
+                    unsafe fn copy(&mut self, $item: &Self::Item) -> Self::Item {
+                        $new(self.region.copy($inner))
+                    }
+                    #[inline(always)]
+                    fn clear(&mut self) {
+                        self.region.clear();
+                    }
+                    #[inline]
+                    fn clear_retaining(&mut self) {
+                        self.region.clear_retaining();
+                    }
+                    #[inline]
+                    fn stashed_chunks(&self) -> usize {
+                        self.region.stashed_chunks()
+                    }
+                    #[cfg(feature = "hooks")]
+                    #[inline]
+                    fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                        self.region.set_hook(hook);
+                    }
+                    #[inline]
+                    fn checkpoint(&self, marks: &mut Vec<usize>) {
+                        self.region.checkpoint(marks);
+                    }
+                    #[inline]
+                    unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                        self.region.rollback(marks);
+                    }
+                    #[inline(always)]
+                    fn item_heap_size($item: &Self::Item) -> usize {
+                        R::item_heap_size($inner)
+                    }
+                    #[inline(always)]
+                    fn reserve_items<'a, I>(&mut self, items: I)
+                    where
+                        Self: 'a,
+                        I: Iterator<Item=&'a Self::Item>+Clone,
+                    {
+                        self.region.reserve_items(items.map(|$item| $inner));
+                    }
+
+                    fn reserve_regions<'a, I>(&mut self, regions: I)
+                    where
+                        Self: 'a,
+                        I: Iterator<Item = &'a Self> + Clone,
+                    {
+                        self.region.reserve_regions(regions.map(|r| &r.region));
+                    }
+                    #[inline]
+                    fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                        self.region.heap_size_paths(path, callback)
+                    }
+                    #[inline]
+                    fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                        self.region.heap_size(callback)
+                    }
+                }
+
+                impl<R: RegionCapacity> RegionCapacity for $region<R> {
+                    type Capacity = R::Capacity;
+                    #[inline]
+                    fn measure_item($item: &Self::Item) -> Self::Capacity {
+                        R::measure_item($inner)
+                    }
+                    #[inline]
+                    fn measure(&self) -> Self::Capacity {
+                        self.region.measure()
+                    }
+                    #[inline]
+                    fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                        self.region.reserve_capacity(capacity);
+                    }
+                }
+
+                unsafe impl<R: TrivialRegion> TrivialRegion for $region<R> { }
+
+                impl<T: Columnation> Columnation for $wrapper<T> {
+                    type InnerRegion = $region<T::InnerRegion>;
+                }
+            );
+        }
+
+        wrapper_columnation!(ReverseRegion, Reverse, item => &item.0, Reverse);
+        wrapper_columnation!(ManuallyDropRegion, ManuallyDrop, item => &**item, ManuallyDrop::new);
+    }
+
+    /// Implementations for `Range<T>`, `RangeInclusive<T>` and `Bound<T>` with `T: Columnation`.
+    pub mod range {
+
+        use std::iter::once;
+        use std::ops::{Bound, Range, RangeInclusive};
+
+        use super::{CapacityPlan, Columnation, Region, RegionCapacity, TrivialRegion};
+
+        /// Region allocation for `Range<T>`, which copies both endpoints in to the region of `T`.
+        #[derive(Default)]
+        pub struct RangeRegion<R: Region> {
+            region: R,
+        }
+
+        impl<R: Region> Region for RangeRegion<R> {
+            type Item = Range<R::Item>;
+            const SHARES_MEMORY: bool = R::SHARES_MEMORY;
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                self.region.copy(&item.start) .. self.region.copy(&item.end)
+            }
+            #[inline(always)]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                R::item_heap_size(&item.start) + R::item_heap_size(&item.end)
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve_items(items.flat_map(|x| once(&x.start).chain(once(&x.end))));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve_regions(regions.map(|r| &r.region));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                self.region.heap_size_paths(path, callback)
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl<R: RegionCapacity> RegionCapacity for RangeRegion<R> {
+            type Capacity = R::Capacity;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                let mut capacity = R::measure_item(&item.start);
+                capacity.add(&R::measure_item(&item.end));
+                capacity
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve_capacity(capacity);
+            }
+        }
+
+        unsafe impl<R: TrivialRegion> TrivialRegion for RangeRegion<R> { }
+
+        impl<T: Columnation> Columnation for Range<T> {
+            type InnerRegion = RangeRegion<T::InnerRegion>;
+        }
+
+        /// Region allocation for `RangeInclusive<T>`, which copies both endpoints in to the region of `T`.
+        ///
+        /// Copies are constructed from their endpoints, and so do not reflect whether the
+        /// original range was exhausted by iteration.
+        #[derive(Default)]
+        pub struct RangeInclusiveRegion<R: Region> {
+            region: R,
+        }
+
+        impl<R: Region> Region for RangeInclusiveRegion<R> {
+            type Item = RangeInclusive<R::Item>;
+            const SHARES_MEMORY: bool = R::SHARES_MEMORY;
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                RangeInclusive::new(self.region.copy(item.start()), self.region.copy(item.end()))
+            }
+            #[inline(always)]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                R::item_heap_size(item.start()) + R::item_heap_size(item.end())
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve_items(items.flat_map(|x| once(x.start()).chain(once(x.end()))));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve_regions(regions.map(|r| &r.region));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                self.region.heap_size_paths(path, callback)
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl<R: RegionCapacity> RegionCapacity for RangeInclusiveRegion<R> {
+            type Capacity = R::Capacity;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                let mut capacity = R::measure_item(item.start());
+                capacity.add(&R::measure_item(item.end()));
+                capacity
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve_capacity(capacity);
+            }
+        }
+
+        impl<T: Columnation> Columnation for RangeInclusive<T> {
+            type InnerRegion = RangeInclusiveRegion<T::InnerRegion>;
+        }
+
+        /// Region allocation for `Bound<T>`, which copies any endpoint in to the region of `T`.
+        #[derive(Default)]
+        pub struct BoundRegion<R: Region> {
+            region: R,
+        }
+
+        impl<R: Region> Region for BoundRegion<R> {
+            type Item = Bound<R::Item>;
+            const SHARES_MEMORY: bool = R::SHARES_MEMORY;
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                match item {
+                    Bound::Included(item) => Bound::Included(self.region.copy(item)),
+                    Bound::Excluded(item) => Bound::Excluded(self.region.copy(item)),
+                    Bound::Unbounded => Bound::Unbounded,
+                }
+            }
+            #[inline(always)]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                match item {
+                    Bound::Included(item) | Bound::Excluded(item) => R::item_heap_size(item),
+                    Bound::Unbounded => 0,
+                }
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve_items(items.flat_map(|x| match x {
+                    Bound::Included(item) | Bound::Excluded(item) => Some(item),
+                    Bound::Unbounded => None,
+                }));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve_regions(regions.map(|r| &r.region));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                self.region.heap_size_paths(path, callback)
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl<R: RegionCapacity> RegionCapacity for BoundRegion<R> {
+            type Capacity = R::Capacity;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                match item {
+                    Bound::Included(item) | Bound::Excluded(item) => R::measure_item(item),
+                    Bound::Unbounded => Default::default(),
+                }
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve_capacity(capacity);
+            }
+        }
+
+        unsafe impl<R: TrivialRegion> TrivialRegion for BoundRegion<R> { }
+
+        impl<T: Columnation> Columnation for Bound<T> {
+            type InnerRegion = BoundRegion<T::InnerRegion>;
+        }
+    }
+
+    /// Implementations for `Option<T: Columnation>`.
     pub mod option {
 
-        use super::{Columnation, Region};
+        use super::{Columnation, Region, RegionCapacity, TrivialRegion};
+
+        #[derive(Default)]
+        pub struct OptionRegion<R: Region> {
+            region: R,
+        }
+
+        impl<R: Region> Region for OptionRegion<R> {
+            type Item = Option<R::Item>;
+            const SHARES_MEMORY: bool = R::SHARES_MEMORY;
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                item.as_ref().map(|inner| self.region.copy(inner))
+            }
+            #[inline(always)]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.as_ref().map_or(0, R::item_heap_size)
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve_items(items.flat_map(|x| x.as_ref()));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve_regions(regions.map(|r| &r.region));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                self.region.heap_size_paths(path, callback)
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl<R: RegionCapacity> RegionCapacity for OptionRegion<R> {
+            type Capacity = R::Capacity;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                item.as_ref().map(R::measure_item).unwrap_or_default()
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve_capacity(capacity);
+            }
+        }
+
+        unsafe impl<R: TrivialRegion> TrivialRegion for OptionRegion<R> { }
+
+        impl<T: Columnation> Columnation for Option<T> {
+            type InnerRegion = OptionRegion<T::InnerRegion>;
+        }
+    }
+
+    /// Implementations for `Result<T: Columnation, E: Columnation>`.
+    pub mod result {
+
+        use super::{Columnation, Region, RegionCapacity, with_segment, TrivialRegion};
+
+        #[derive(Default)]
+        pub struct ResultRegion<R1: Region, R2: Region> {
+            region1: R1,
+            region2: R2,
+        }
+
+
+        impl<R1: Region, R2: Region> Region for ResultRegion<R1, R2> {
+            type Item = Result<R1::Item, R2::Item>;
+            const SHARES_MEMORY: bool = R1::SHARES_MEMORY || R2::SHARES_MEMORY;
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                match item {
+                    Ok(item) => { Ok(self.region1.copy(item)) },
+                    Err(item) => { Err(self.region2.copy(item)) },
+                }
+            }
+            #[inline(always)]
+            fn clear(&mut self) {
+                self.region1.clear();
+                self.region2.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region1.clear_retaining();
+                self.region2.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region1.stashed_chunks() + self.region2.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region1.set_hook(hook);
+                self.region2.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region1.checkpoint(marks);
+                self.region2.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region1.rollback(marks);
+                self.region2.rollback(marks);
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                match item {
+                    Ok(item) => R1::item_heap_size(item),
+                    Err(item) => R2::item_heap_size(item),
+                }
+            }
+            #[inline]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                let items2 = items.clone();
+                self.region1.reserve_items(items2.flat_map(|x| x.as_ref().ok()));
+                self.region2.reserve_items(items.flat_map(|x| x.as_ref().err()));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region1.reserve_regions(regions.clone().map(|r| &r.region1));
+                self.region2.reserve_regions(regions.map(|r| &r.region2));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".ok", |path| self.region1.heap_size_paths(path, &mut callback));
+                with_segment(path, ".err", |path| self.region2.heap_size_paths(path, &mut callback));
+            }
+            #[inline]
+            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+                self.region1.heap_size(&mut callback);
+                self.region2.heap_size(callback)
+            }
+        }
+
+        impl<R1: RegionCapacity, R2: RegionCapacity> RegionCapacity for ResultRegion<R1, R2> {
+            type Capacity = (R1::Capacity, R2::Capacity);
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                match item {
+                    Ok(item) => (R1::measure_item(item), Default::default()),
+                    Err(item) => (Default::default(), R2::measure_item(item)),
+                }
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                (self.region1.measure(), self.region2.measure())
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region1.reserve_capacity(&capacity.0);
+                self.region2.reserve_capacity(&capacity.1);
+            }
+        }
+
+        unsafe impl<R1: TrivialRegion, R2: TrivialRegion> TrivialRegion for ResultRegion<R1, R2> { }
+
+        impl<T: Columnation, E: Columnation> Columnation for Result<T, E> {
+            type InnerRegion = ResultRegion<T::InnerRegion, E::InnerRegion>;
+        }
+    }
+
+    /// Implementations for `Vec<T: Columnation>`.
+    pub mod vec {
+
+        use super::{Columnation, Region, RegionCapacity, StableRegion, with_segment};
+
+        /// Region allocation for the contents of `Vec<T>` types.
+        ///
+        /// Items `T` are stored in stable contiguous memory locations,
+        /// and then a `Vec<T>` referencing them is falsified.
+        pub struct VecRegion<T: Columnation> {
+            /// Region for stable memory locations for `T` items.
+            region: StableRegion<T>,
+            /// Any inner region allocations.
+            inner: T::InnerRegion,
+        }
+
+        // Manually implement `Default` as `T` may not implement it.
+        impl<T: Columnation> Default for VecRegion<T> {
+            fn default() -> Self {
+                VecRegion {
+                    region: StableRegion::<T>::default(),
+                    inner: T::InnerRegion::default(),
+                }
+            }
+        }
+
+        impl<T: Columnation> Columnation for Vec<T> {
+            type InnerRegion = VecRegion<T>;
+        }
+
+        impl<T: Columnation> VecRegion<T> {
+            /// Copies a sequence of elements in to the region, and falsifies a `Vec<T>` of them.
+            ///
+            /// This allows other sequence types that can be built from a `Vec<T>`, like
+            /// `VecDeque<T>`, to reuse this region.
+            ///
+            /// # Safety
+            ///
+            /// The same constraints apply as for [Region::copy].
+            #[inline(always)]
+            pub unsafe fn copy_elements<'a, I>(&mut self, elements: I) -> Vec<T>
+            where
+                T: 'a,
+                I: Iterator<Item=&'a T>+ExactSizeIterator,
+            {
+                // TODO: Some types `T` should just be cloned, with `copy_slice`.
+                // E.g. types that are `Copy` or vecs of ZSTs.
+                let inner = &mut self.inner;
+                let slice = self.region.copy_iter(elements.map(|element| inner.copy(element)));
+                let len = slice.len();
+                Vec::from_raw_parts(slice.as_mut_ptr(), len, len)
+            }
+            /// The capacity that copying `elements` with `copy_elements` uses.
+            #[inline(always)]
+            pub fn measure_elements<'a, I>(elements: I) -> <Self as RegionCapacity>::Capacity
+            where
+                T: 'a,
+                T::InnerRegion: RegionCapacity,
+                I: Iterator<Item=&'a T>+ExactSizeIterator,
+            {
+                (elements.len(), T::InnerRegion::measure_items(elements))
+            }
+            /// The bytes of region memory that copying `elements` with `copy_elements` uses.
+            #[inline(always)]
+            pub fn elements_heap_size<'a, I>(elements: I) -> usize
+            where
+                T: 'a,
+                I: Iterator<Item=&'a T>,
+            {
+                elements.map(|element| std::mem::size_of::<T>() + T::InnerRegion::item_heap_size(element)).sum()
+            }
+            /// Ensures the region can absorb `elements` without reallocation.
+            #[inline(always)]
+            pub fn reserve_elements<'a, I>(&mut self, elements: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a T>+Clone,
+            {
+                self.region.reserve(elements.clone().count());
+                self.inner.reserve_items(elements);
+            }
+        }
+
+        impl<T: Columnation> Region for VecRegion<T> {
+            type Item = Vec<T>;
+            const SHARES_MEMORY: bool = T::InnerRegion::SHARES_MEMORY;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+                self.inner.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+                self.inner.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks() + self.inner.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+                self.inner.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+                self.inner.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+                self.inner.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                self.copy_elements(item.iter())
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                VecRegion::<T>::elements_heap_size(item.iter())
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.reserve_elements(items.flat_map(|x| x.iter()));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
+                self.inner.reserve_regions(regions.map(|r| &r.inner));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".vec", |path| {
+                    self.inner.heap_size_paths(path, &mut callback);
+                    with_segment(path, ".elements", |path| {
+                        let path = &*path;
+                        self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                    });
+                });
+            }
+            #[inline]
+            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+                self.inner.heap_size(&mut callback);
+                self.region.heap_size(callback);
+            }
+        }
+
+        impl<T: Columnation> RegionCapacity for VecRegion<T>
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            type Capacity = (usize, <T::InnerRegion as RegionCapacity>::Capacity);
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                VecRegion::<T>::measure_elements(item.iter())
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                (self.region.len(), self.inner.measure())
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(capacity.0);
+                self.inner.reserve_capacity(&capacity.1);
+            }
+        }
+    }
+
+    /// Implementations for `VecDeque<T: Columnation>`.
+    ///
+    /// Elements are laid out contiguously in logical order, and a `VecDeque<T>`
+    /// is built from the falsified `Vec<T>`, which does not reallocate.
+    pub mod vec_deque {
+
+        use std::collections::VecDeque;
+
+        use super::{Columnation, Region, RegionCapacity};
+        use super::vec::VecRegion;
+
+        /// Region allocation for the contents of `VecDeque<T>` types.
+        pub struct VecDequeRegion<T: Columnation> {
+            region: VecRegion<T>,
+        }
+
+        // Manually implement `Default` as `T` may not implement it.
+        impl<T: Columnation> Default for VecDequeRegion<T> {
+            fn default() -> Self {
+                VecDequeRegion { region: VecRegion::default() }
+            }
+        }
+
+        impl<T: Columnation> Columnation for VecDeque<T> {
+            type InnerRegion = VecDequeRegion<T>;
+        }
+
+        impl<T: Columnation> Region for VecDequeRegion<T> {
+            type Item = VecDeque<T>;
+            const SHARES_MEMORY: bool = T::InnerRegion::SHARES_MEMORY;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                VecDeque::from(self.region.copy_elements(item.iter()))
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                VecRegion::<T>::elements_heap_size(item.iter())
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve_elements(items.flat_map(|x| x.iter()));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve_regions(regions.map(|r| &r.region));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                self.region.heap_size_paths(path, callback)
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl<T: Columnation> RegionCapacity for VecDequeRegion<T>
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            type Capacity = <VecRegion<T> as RegionCapacity>::Capacity;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                VecRegion::<T>::measure_elements(item.iter())
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve_capacity(capacity);
+            }
+        }
+    }
+
+    /// Implementations for `BinaryHeap<T: Columnation>`.
+    ///
+    /// Elements are laid out contiguously in the heap's own order, and a `BinaryHeap<T>`
+    /// is built from the falsified `Vec<T>`, which does not reallocate.
+    pub mod binary_heap {
+
+        use std::collections::BinaryHeap;
+
+        use super::{Columnation, Region, RegionCapacity};
+        use super::vec::VecRegion;
+
+        /// Region allocation for the contents of `BinaryHeap<T>` types.
+        pub struct BinaryHeapRegion<T: Columnation> {
+            region: VecRegion<T>,
+        }
+
+        // Manually implement `Default` as `T` may not implement it.
+        impl<T: Columnation> Default for BinaryHeapRegion<T> {
+            fn default() -> Self {
+                BinaryHeapRegion { region: VecRegion::default() }
+            }
+        }
+
+        impl<T: Columnation+Ord> Columnation for BinaryHeap<T> {
+            type InnerRegion = BinaryHeapRegion<T>;
+        }
+
+        impl<T: Columnation+Ord> Region for BinaryHeapRegion<T> {
+            type Item = BinaryHeap<T>;
+            const SHARES_MEMORY: bool = T::InnerRegion::SHARES_MEMORY;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                // The heap is built by pushing each element in place, in to its own slot, as
+                // comparisons may panic. Unwinding must leak the falsified allocation rather
+                // than drop it, and the push of each slot in turn never reallocates. The
+                // elements are already in heap order, so pushing them moves nothing.
+                let mut elements = std::mem::ManuallyDrop::new(self.region.copy_elements(item.iter()));
+                let (pointer, len) = (elements.as_mut_ptr(), elements.len());
+                let mut heap = std::mem::ManuallyDrop::new(BinaryHeap::from(Vec::from_raw_parts(pointer, 0, len)));
+                for index in 0 .. len {
+                    heap.push(std::ptr::read(pointer.add(index)));
+                }
+                std::mem::ManuallyDrop::into_inner(heap)
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                VecRegion::<T>::elements_heap_size(item.iter())
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve_elements(items.flat_map(|x| x.iter()));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve_regions(regions.map(|r| &r.region));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                self.region.heap_size_paths(path, callback)
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl<T: Columnation+Ord> RegionCapacity for BinaryHeapRegion<T>
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            type Capacity = <VecRegion<T> as RegionCapacity>::Capacity;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                VecRegion::<T>::measure_elements(item.iter())
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve_capacity(capacity);
+            }
+        }
+    }
+
+    /// Implementations for `SmallVec<[T: Columnation; K: usize]>`.
+    ///
+    /// This parallels the `Vec` implementation, but only moves data to the stable region if
+    /// the smallvec spills from its inline storage.
+    pub mod smallvec {
+
+        use smallvec::SmallVec;
+
+        use super::{Columnation, Region, RegionCapacity, StableRegion, with_segment};
+
+        /// Region allocation for the contents of `SmallVec<[T; K]>` types.
+        ///
+        /// Items `T` are stored in stable contiguous memory locations,
+        /// and then a `SmallVec<[T; K]>` referencing them is falsified.
+        pub struct SmallVecRegion<T: Columnation, const K: usize> {
+            /// Region for stable memory locations for `T` items.
+            region: StableRegion<T>,
+            /// Any inner region allocations.
+            inner: T::InnerRegion,
+        }
+
+        // Manually implement `Default` as `T` may not implement it.
+        impl<T: Columnation, const K: usize> Default for SmallVecRegion<T, K> {
+            fn default() -> Self {
+                SmallVecRegion {
+                    region: StableRegion::<T>::default(),
+                    inner: T::InnerRegion::default(),
+                }
+            }
+        }
+
+        impl<T: Columnation+Clone, const K: usize> Columnation for SmallVec<[T; K]> {
+            type InnerRegion = SmallVecRegion<T, K>;
+        }
+
+        impl<T: Columnation+Clone, const K: usize> Region for SmallVecRegion<T, K> {
+            type Item = SmallVec<[T; K]>;
+            const SHARES_MEMORY: bool = T::InnerRegion::SHARES_MEMORY;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+                self.inner.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+                self.inner.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks() + self.inner.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+                self.inner.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+                self.inner.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+                self.inner.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                // TODO: Some types `T` should just be cloned, with `copy_slice`.
+                // E.g. types that are `Copy` or vecs of ZSTs.
+                let inner = &mut self.inner;
+                let elements = item.iter().map(|element| inner.copy(element));
+                if item.len() <= item.inline_size() {
+                    // The resulting SmallVec should not spill, so we avoid `from_iter` and friends
+                    // because they try to use the iterator's `size_hint` method to right-size the
+                    // capacity. If the hint is too large we could end up accidentally spilling.
+                    let mut item_new = SmallVec::new();
+                    for element in elements {
+                        item_new.push(element);
+                    }
+                    assert!(!item_new.spilled());
+                    item_new
+                } else {
+                    let slice = self.region.copy_iter(elements);
+                    SmallVec::from_raw_parts(slice.as_mut_ptr(), item.len(), item.len())
+                }
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                // Only items that do not fit inline are copied to the region.
+                let spilled = if item.len() > item.inline_size() { item.len() } else { 0 };
+                spilled * std::mem::size_of::<T>() + item.iter().map(T::InnerRegion::item_heap_size).sum::<usize>()
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                // Copies spill exactly when they do not fit inline, whether or not the item itself
+                // spilled, and then need room for all of their elements rather than one per item.
+                // Elements are copied in to the inner region in either case.
+                self.region.reserve(items.clone().filter(|x| x.len() > x.inline_size()).map(|x| x.len()).sum());
+                self.inner.reserve_items(items.flat_map(|x| x.iter()));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
+                self.inner.reserve_regions(regions.map(|r| &r.inner));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".smallvec", |path| {
+                    self.inner.heap_size_paths(path, &mut callback);
+                    with_segment(path, ".elements", |path| {
+                        let path = &*path;
+                        self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                    });
+                });
+            }
+            #[inline]
+            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+                self.inner.heap_size(&mut callback);
+                self.region.heap_size(callback);
+            }
+        }
+
+        impl<T: Columnation+Clone, const K: usize> RegionCapacity for SmallVecRegion<T, K>
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            type Capacity = (usize, <T::InnerRegion as RegionCapacity>::Capacity);
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                // Only items that do not fit inline are copied to the region.
+                let spilled = if item.len() > item.inline_size() { item.len() } else { 0 };
+                (spilled, T::InnerRegion::measure_items(item.iter()))
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                (self.region.len(), self.inner.measure())
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(capacity.0);
+                self.inner.reserve_capacity(&capacity.1);
+            }
+        }
+    }
+
+    /// Implementations for `Box<T: Columnation>`, `Box<[T: Columnation]>`, and `Box<str>`.
+    pub mod boxed {
+
+        use super::{Columnation, Region, RegionCapacity, StableRegion, with_segment};
+
+        /// Region allocation for the contents of `Box<T>` types.
+        ///
+        /// Items `T` are stored in stable memory locations,
+        /// and then a `Box<T>` referencing them is falsified.
+        pub struct BoxRegion<T: Columnation> {
+            /// Region for stable memory locations for `T` items.
+            region: StableRegion<T>,
+            /// Any inner region allocations.
+            inner: T::InnerRegion,
+        }
+
+        // Manually implement `Default` as `T` may not implement it.
+        impl<T: Columnation> Default for BoxRegion<T> {
+            fn default() -> Self {
+                BoxRegion {
+                    region: StableRegion::<T>::default(),
+                    inner: T::InnerRegion::default(),
+                }
+            }
+        }
+
+        impl<T: Columnation> Columnation for Box<T> {
+            type InnerRegion = BoxRegion<T>;
+        }
+
+        impl<T: Columnation> Region for BoxRegion<T> {
+            type Item = Box<T>;
+            const SHARES_MEMORY: bool = T::InnerRegion::SHARES_MEMORY;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+                self.inner.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+                self.inner.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks() + self.inner.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+                self.inner.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+                self.inner.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+                self.inner.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                let element = self.inner.copy(item);
+                let slice = self.region.copy_iter(std::iter::once(element));
+                Box::from_raw(slice.as_mut_ptr())
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                std::mem::size_of::<T>() + T::InnerRegion::item_heap_size(item)
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve(items.clone().count());
+                self.inner.reserve_items(items.map(|x| &**x));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
+                self.inner.reserve_regions(regions.map(|r| &r.inner));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".box", |path| {
+                    self.inner.heap_size_paths(path, &mut callback);
+                    with_segment(path, ".elements", |path| {
+                        let path = &*path;
+                        self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                    });
+                });
+            }
+            #[inline]
+            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+                self.inner.heap_size(&mut callback);
+                self.region.heap_size(callback);
+            }
+        }
+
+        impl<T: Columnation> RegionCapacity for BoxRegion<T>
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            type Capacity = (usize, <T::InnerRegion as RegionCapacity>::Capacity);
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                (1, T::InnerRegion::measure_item(item))
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                (self.region.len(), self.inner.measure())
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(capacity.0);
+                self.inner.reserve_capacity(&capacity.1);
+            }
+        }
+
+        /// Region allocation for the contents of `Box<[T]>` types.
+        ///
+        /// Items `T` are stored in stable contiguous memory locations,
+        /// and then a `Box<[T]>` referencing them is falsified.
+        pub struct BoxSliceRegion<T: Columnation> {
+            /// Region for stable memory locations for `T` items.
+            region: StableRegion<T>,
+            /// Any inner region allocations.
+            inner: T::InnerRegion,
+        }
+
+        // Manually implement `Default` as `T` may not implement it.
+        impl<T: Columnation> Default for BoxSliceRegion<T> {
+            fn default() -> Self {
+                BoxSliceRegion {
+                    region: StableRegion::<T>::default(),
+                    inner: T::InnerRegion::default(),
+                }
+            }
+        }
+
+        impl<T: Columnation> Columnation for Box<[T]> {
+            type InnerRegion = BoxSliceRegion<T>;
+        }
+
+        impl<T: Columnation> Region for BoxSliceRegion<T> {
+            type Item = Box<[T]>;
+            const SHARES_MEMORY: bool = T::InnerRegion::SHARES_MEMORY;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+                self.inner.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+                self.inner.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks() + self.inner.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+                self.inner.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+                self.inner.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+                self.inner.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                let inner = &mut self.inner;
+                let slice = self.region.copy_iter(item.iter().map(|element| inner.copy(element)));
+                Box::from_raw(slice)
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.len() * std::mem::size_of::<T>() + item.iter().map(T::InnerRegion::item_heap_size).sum::<usize>()
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve(items.clone().map(|x| x.len()).sum());
+                self.inner.reserve_items(items.flat_map(|x| x.iter()));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
+                self.inner.reserve_regions(regions.map(|r| &r.inner));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".box", |path| {
+                    self.inner.heap_size_paths(path, &mut callback);
+                    with_segment(path, ".elements", |path| {
+                        let path = &*path;
+                        self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                    });
+                });
+            }
+            #[inline]
+            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+                self.inner.heap_size(&mut callback);
+                self.region.heap_size(callback);
+            }
+        }
+
+        impl<T: Columnation> RegionCapacity for BoxSliceRegion<T>
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            type Capacity = (usize, <T::InnerRegion as RegionCapacity>::Capacity);
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                (item.len(), T::InnerRegion::measure_items(item.iter()))
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                (self.region.len(), self.inner.measure())
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(capacity.0);
+                self.inner.reserve_capacity(&capacity.1);
+            }
+        }
+
+        /// Region allocation for `Box<str>` data.
+        ///
+        /// Content bytes are stored in stable contiguous memory locations,
+        /// and then a `Box<str>` referencing them is falsified.
+        #[derive(Default)]
+        pub struct BoxStrRegion {
+            region: StableRegion<u8>,
+        }
+
+        impl Columnation for Box<str> {
+            type InnerRegion = BoxStrRegion;
+        }
+
+        impl Region for BoxStrRegion {
+            type Item = Box<str>;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                let bytes = self.region.copy_slice(item.as_bytes());
+                Box::from_raw(std::str::from_utf8_unchecked_mut(bytes))
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.len()
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve(items.map(|x| x.len()).sum());
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve(regions.map(|r| r.region.len()).sum());
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".box.bytes", |path| {
+                    let path = &*path;
+                    self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                });
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl RegionCapacity for BoxStrRegion {
+            type Capacity = usize;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                item.len()
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.len()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(*capacity);
+            }
+        }
+    }
+
+    /// Implementations for `Cow<str>` and `Cow<[T: Columnation]>`.
+    ///
+    /// Items are always copied as `Cow::Owned`, whether or not they were borrowed.
+    pub mod cow {
+
+        use std::borrow::Cow;
+
+        use super::{Columnation, Region, RegionCapacity, StableRegion, with_segment};
+
+        /// Region allocation for `Cow<str>` data.
+        ///
+        /// Content bytes are stored in stable contiguous memory locations,
+        /// and then an owned `String` referencing them is falsified.
+        pub struct CowStrRegion<'c> {
+            region: StableRegion<u8>,
+            phantom: std::marker::PhantomData<&'c str>,
+        }
+
+        impl<'c> Default for CowStrRegion<'c> {
+            fn default() -> Self {
+                CowStrRegion {
+                    region: StableRegion::default(),
+                    phantom: std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<'c> Columnation for Cow<'c, str> {
+            type InnerRegion = CowStrRegion<'c>;
+        }
+
+        impl<'c> Region for CowStrRegion<'c> {
+            type Item = Cow<'c, str>;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+                let bytes = self.region.copy_slice(item.as_bytes());
+                Cow::Owned(String::from_raw_parts(bytes.as_mut_ptr(), item.len(), item.len()))
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.len()
+            }
+            #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.region.reserve(items.map(|x| x.len()).sum());
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.region.reserve(regions.map(|r| r.region.len()).sum());
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".cow.bytes", |path| {
+                    let path = &*path;
+                    self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                });
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        impl<'c> RegionCapacity for CowStrRegion<'c> {
+            type Capacity = usize;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                item.len()
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.len()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(*capacity);
+            }
+        }
+
+        /// Region allocation for the contents of `Cow<[T]>` types.
+        ///
+        /// Items `T` are stored in stable contiguous memory locations,
+        /// and then an owned `Vec<T>` referencing them is falsified.
+        pub struct CowSliceRegion<'c, T: Columnation> {
+            /// Region for stable memory locations for `T` items.
+            region: StableRegion<T>,
+            /// Any inner region allocations.
+            inner: T::InnerRegion,
+            phantom: std::marker::PhantomData<&'c [T]>,
+        }
+
+        // Manually implement `Default` as `T` may not implement it.
+        impl<'c, T: Columnation> Default for CowSliceRegion<'c, T> {
+            fn default() -> Self {
+                CowSliceRegion {
+                    region: StableRegion::<T>::default(),
+                    inner: T::InnerRegion::default(),
+                    phantom: std::marker::PhantomData,
+                }
+            }
+        }
 
-        #[derive(Default)]
-        pub struct OptionRegion<R: Region> {
-            region: R,
+        impl<'c, T: Columnation+Clone> Columnation for Cow<'c, [T]> {
+            type InnerRegion = CowSliceRegion<'c, T>;
         }
 
-        impl<R: Region> Region for OptionRegion<R> {
-            type Item = Option<R::Item>;
+        impl<'c, T: Columnation+Clone> Region for CowSliceRegion<'c, T> {
+            type Item = Cow<'c, [T]>;
+            const SHARES_MEMORY: bool = T::InnerRegion::SHARES_MEMORY;
+            #[inline]
+            fn clear(&mut self) {
+                self.region.clear();
+                self.inner.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+                self.inner.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks() + self.inner.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+                self.inner.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+                self.inner.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+                self.inner.rollback(marks);
+            }
             #[inline(always)]
             unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
-                item.as_ref().map(|inner| self.region.copy(inner))
+                let inner = &mut self.inner;
+                let slice = self.region.copy_iter(item.iter().map(|element| inner.copy(element)));
+                Cow::Owned(Vec::from_raw_parts(slice.as_mut_ptr(), item.len(), item.len()))
             }
             #[inline(always)]
-            fn clear(&mut self) {
-                self.region.clear();
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.len() * std::mem::size_of::<T>() + item.iter().map(T::InnerRegion::item_heap_size).sum::<usize>()
             }
             #[inline(always)]
             fn reserve_items<'a, I>(&mut self, items: I)
@@ -522,7 +4916,8 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item=&'a Self::Item>+Clone,
             {
-                self.region.reserve_items(items.flat_map(|x| x.as_ref()));
+                self.region.reserve(items.clone().map(|x| x.len()).sum());
+                self.inner.reserve_items(items.flat_map(|x| x.iter()));
             }
 
             fn reserve_regions<'a, I>(&mut self, regions: I)
@@ -530,54 +4925,220 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item = &'a Self> + Clone,
             {
-                self.region.reserve_regions(regions.map(|r| &r.region));
+                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
+                self.inner.reserve_regions(regions.map(|r| &r.inner));
             }
             #[inline]
-            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
-                self.region.heap_size(callback)
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".cow", |path| {
+                    self.inner.heap_size_paths(path, &mut callback);
+                    with_segment(path, ".elements", |path| {
+                        let path = &*path;
+                        self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                    });
+                });
+            }
+            #[inline]
+            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+                self.inner.heap_size(&mut callback);
+                self.region.heap_size(callback);
             }
         }
 
-        impl<T: Columnation> Columnation for Option<T> {
-            type InnerRegion = OptionRegion<T::InnerRegion>;
+        impl<'c, T: Columnation+Clone> RegionCapacity for CowSliceRegion<'c, T>
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            type Capacity = (usize, <T::InnerRegion as RegionCapacity>::Capacity);
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                (item.len(), T::InnerRegion::measure_items(item.iter()))
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                (self.region.len(), self.inner.measure())
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(capacity.0);
+                self.inner.reserve_capacity(&capacity.1);
+            }
         }
     }
 
-    /// Implementations for `Result<T: Columnation, E: Columnation>`.
-    pub mod result {
+    /// Implementations for `[T: Columnation; N: usize]`.
+    pub mod array {
 
-        use super::{Columnation, Region};
+        use super::{Columnation, Region, RegionCapacity, with_segment, TrivialRegion};
 
-        #[derive(Default)]
-        pub struct ResultRegion<R1: Region, R2: Region> {
-            region1: R1,
-            region2: R2,
+        /// Region allocation for the contents of `[T; N]` types.
+        ///
+        /// Each element is copied in to the region of `T`.
+        pub struct ArrayRegion<T: Columnation, const N: usize> {
+            /// Any inner region allocations.
+            inner: T::InnerRegion,
+        }
+
+        // Manually implement `Default` as `T` may not implement it.
+        impl<T: Columnation, const N: usize> Default for ArrayRegion<T, N> {
+            fn default() -> Self {
+                ArrayRegion {
+                    inner: T::InnerRegion::default(),
+                }
+            }
         }
 
+        impl<T: Columnation, const N: usize> Columnation for [T; N] {
+            type InnerRegion = ArrayRegion<T, N>;
+        }
 
-        impl<R1: Region, R2: Region> Region for ResultRegion<R1, R2> {
-            type Item = Result<R1::Item, R2::Item>;
+        impl<T: Columnation, const N: usize> Region for ArrayRegion<T, N> {
+            type Item = [T; N];
+            const SHARES_MEMORY: bool = T::InnerRegion::SHARES_MEMORY;
+            #[inline]
+            fn clear(&mut self) {
+                self.inner.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.inner.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.inner.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.inner.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.inner.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.inner.rollback(marks);
+            }
             #[inline(always)]
             unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
-                match item {
-                    Ok(item) => { Ok(self.region1.copy(item)) },
-                    Err(item) => { Err(self.region2.copy(item)) },
-                }
+                let inner = &mut self.inner;
+                std::array::from_fn(|index| inner.copy(&item[index]))
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.iter().map(T::InnerRegion::item_heap_size).sum()
             }
             #[inline(always)]
+            fn reserve_items<'a, I>(&mut self, items: I)
+            where
+                Self: 'a,
+                I: Iterator<Item=&'a Self::Item>+Clone,
+            {
+                self.inner.reserve_items(items.flat_map(|x| x.iter()));
+            }
+
+            fn reserve_regions<'a, I>(&mut self, regions: I)
+            where
+                Self: 'a,
+                I: Iterator<Item = &'a Self> + Clone,
+            {
+                self.inner.reserve_regions(regions.map(|r| &r.inner));
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".array", |path| self.inner.heap_size_paths(path, callback));
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.inner.heap_size(callback)
+            }
+        }
+
+        impl<T: Columnation, const N: usize> RegionCapacity for ArrayRegion<T, N>
+        where
+            T::InnerRegion: RegionCapacity,
+        {
+            type Capacity = <T::InnerRegion as RegionCapacity>::Capacity;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                T::InnerRegion::measure_items(item.iter())
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.inner.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.inner.reserve_capacity(capacity);
+            }
+        }
+
+        unsafe impl<T: Columnation, const N: usize> TrivialRegion for ArrayRegion<T, N> where T::InnerRegion: TrivialRegion { }
+    }
+
+    /// Implementation for `String`.
+    pub mod string {
+
+        use super::{Columnation, Region, RegionCapacity, StableRegion, with_segment};
+
+        /// Region allocation for `String` data.
+        ///
+        /// Content bytes are stored in stable contiguous memory locations,
+        /// and then a `String` referencing them is falsified.
+        #[derive(Default)]
+        pub struct StringStack {
+            region: StableRegion<u8>,
+        }
+
+        impl Columnation for String {
+            type InnerRegion = StringStack;
+        }
+
+        impl Region for StringStack {
+            type Item = String;
+            #[inline]
             fn clear(&mut self) {
-                self.region1.clear();
-                self.region2.clear();
+                self.region.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
             }
+            #[cfg(feature = "hooks")]
             #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            // Removing `(always)` is a 20% performance regression in
+            // the `string10_copy` benchmark.
+            #[inline(always)] unsafe fn copy(&mut self, item: &String) -> String {
+                let bytes = self.region.copy_slice(item.as_bytes());
+                String::from_raw_parts(bytes.as_mut_ptr(), item.len(), item.len())
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.len()
+            }
+            #[inline(always)]
             fn reserve_items<'a, I>(&mut self, items: I)
             where
                 Self: 'a,
                 I: Iterator<Item=&'a Self::Item>+Clone,
             {
-                let items2 = items.clone();
-                self.region1.reserve_items(items2.flat_map(|x| x.as_ref().ok()));
-                self.region2.reserve_items(items.flat_map(|x| x.as_ref().err()));
+                self.region.reserve(items.map(|x| x.len()).sum());
             }
 
             fn reserve_regions<'a, I>(&mut self, regions: I)
@@ -585,65 +5146,114 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item = &'a Self> + Clone,
             {
-                self.region1.reserve_regions(regions.clone().map(|r| &r.region1));
-                self.region2.reserve_regions(regions.map(|r| &r.region2));
+                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
             }
             #[inline]
-            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
-                self.region1.heap_size(&mut callback);
-                self.region2.heap_size(callback)
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".string.bytes", |path| {
+                    let path = &*path;
+                    self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                });
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
             }
         }
 
-        impl<T: Columnation, E: Columnation> Columnation for Result<T, E> {
-            type InnerRegion = ResultRegion<T::InnerRegion, E::InnerRegion>;
+        impl RegionCapacity for StringStack {
+            type Capacity = usize;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                item.len()
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.len()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(*capacity);
+            }
         }
     }
 
-    /// Implementations for `Vec<T: Columnation>`.
-    pub mod vec {
+    /// Implementations for `OsString` and `PathBuf`.
+    ///
+    /// On Unix these are byte buffers, and this parallels the `String` implementation.
+    /// Elsewhere their representation is opaque, and they are retained as clones.
+    pub mod os_string {
 
-        use super::{Columnation, Region, StableRegion};
+        use std::ffi::OsString;
+        use std::path::PathBuf;
 
-        /// Region allocation for the contents of `Vec<T>` types.
+        use super::Columnation;
+        #[cfg(unix)]
+        use super::{Region, RegionCapacity, StableRegion, with_segment};
+        #[cfg(not(unix))]
+        use super::CloneRegion;
+
+        /// Region allocation for `OsString` data.
         ///
-        /// Items `T` are stored in stable contiguous memory locations,
-        /// and then a `Vec<T>` referencing them is falsified.
-        pub struct VecRegion<T: Columnation> {
-            /// Region for stable memory locations for `T` items.
-            region: StableRegion<T>,
-            /// Any inner region allocations.
-            inner: T::InnerRegion,
+        /// Content bytes are stored in stable contiguous memory locations,
+        /// and then an `OsString` referencing them is falsified.
+        #[cfg(unix)]
+        #[derive(Default)]
+        pub struct OsStringStack {
+            region: StableRegion<u8>,
         }
 
-        // Manually implement `Default` as `T` may not implement it.
-        impl<T: Columnation> Default for VecRegion<T> {
-            fn default() -> Self {
-                VecRegion {
-                    region: StableRegion::<T>::default(),
-                    inner: T::InnerRegion::default(),
-                }
+        #[cfg(unix)]
+        impl OsStringStack {
+            /// Copies the bytes of `item` in to the region, and falsifies an `OsString` of them.
+            #[inline(always)]
+            unsafe fn copy_os_str(&mut self, item: &std::ffi::OsStr) -> OsString {
+                use std::os::unix::ffi::{OsStrExt, OsStringExt};
+                let bytes = self.region.copy_slice(item.as_bytes());
+                OsString::from_vec(Vec::from_raw_parts(bytes.as_mut_ptr(), item.len(), item.len()))
             }
         }
 
-        impl<T: Columnation> Columnation for Vec<T> {
-            type InnerRegion = VecRegion<T>;
+        #[cfg(unix)]
+        impl Columnation for OsString {
+            type InnerRegion = OsStringStack;
         }
 
-        impl<T: Columnation> Region for VecRegion<T> {
-            type Item = Vec<T>;
+        #[cfg(unix)]
+        impl Region for OsStringStack {
+            type Item = OsString;
             #[inline]
             fn clear(&mut self) {
                 self.region.clear();
-                self.inner.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
             }
             #[inline(always)]
-            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
-                // TODO: Some types `T` should just be cloned, with `copy_slice`.
-                // E.g. types that are `Copy` or vecs of ZSTs.
-                let inner = &mut self.inner;
-                let slice = self.region.copy_iter(item.iter().map(|element| inner.copy(element)));
-                Vec::from_raw_parts(slice.as_mut_ptr(), item.len(), item.len())
+            unsafe fn copy(&mut self, item: &OsString) -> OsString {
+                self.copy_os_str(item)
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.len()
             }
             #[inline(always)]
             fn reserve_items<'a, I>(&mut self, items: I)
@@ -651,8 +5261,7 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item=&'a Self::Item>+Clone,
             {
-                self.region.reserve(items.clone().count());
-                self.inner.reserve_items(items.flat_map(|x| x.iter()));
+                self.region.reserve(items.map(|x| x.len()).sum());
             }
 
             fn reserve_regions<'a, I>(&mut self, regions: I)
@@ -660,79 +5269,88 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item = &'a Self> + Clone,
             {
-                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
-                self.inner.reserve_regions(regions.map(|r| &r.inner));
+                self.region.reserve(regions.map(|r| r.region.len()).sum());
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".os_string.bytes", |path| {
+                    let path = &*path;
+                    self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                });
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        #[cfg(unix)]
+        impl RegionCapacity for OsStringStack {
+            type Capacity = usize;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                item.len()
             }
             #[inline]
-            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
-                self.inner.heap_size(&mut callback);
-                self.region.heap_size(callback);
+            fn measure(&self) -> Self::Capacity {
+                self.region.len()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(*capacity);
             }
         }
-    }
-
-    /// Implementations for `SmallVec<[T: Columnation; K: usize]>`.
-    ///
-    /// This parallels the `Vec` implementation, but only moves data to the stable region if
-    /// the smallvec spills from its inline storage.
-    pub mod smallvec {
-
-        use smallvec::SmallVec;
-
-        use super::{Columnation, Region, StableRegion};
 
-        /// Region allocation for the contents of `SmallVec<[T; K]>` types.
+        /// Region allocation for `PathBuf` data.
         ///
-        /// Items `T` are stored in stable contiguous memory locations,
-        /// and then a `SmallVec<[T; K]>` referencing them is falsified.
-        pub struct SmallVecRegion<T: Columnation, const K: usize> {
-            /// Region for stable memory locations for `T` items.
-            region: StableRegion<T>,
-            /// Any inner region allocations.
-            inner: T::InnerRegion,
-        }
-
-        // Manually implement `Default` as `T` may not implement it.
-        impl<T: Columnation, const K: usize> Default for SmallVecRegion<T, K> {
-            fn default() -> Self {
-                SmallVecRegion {
-                    region: StableRegion::<T>::default(),
-                    inner: T::InnerRegion::default(),
-                }
-            }
+        /// Content bytes are stored in stable contiguous memory locations,
+        /// and then a `PathBuf` referencing them is falsified.
+        #[cfg(unix)]
+        #[derive(Default)]
+        pub struct PathBufStack {
+            region: OsStringStack,
         }
 
-        impl<T: Columnation+Clone, const K: usize> Columnation for SmallVec<[T; K]> {
-            type InnerRegion = SmallVecRegion<T, K>;
+        #[cfg(unix)]
+        impl Columnation for PathBuf {
+            type InnerRegion = PathBufStack;
         }
 
-        impl<T: Columnation+Clone, const K: usize> Region for SmallVecRegion<T, K> {
-            type Item = SmallVec<[T; K]>;
+        #[cfg(unix)]
+        impl Region for PathBufStack {
+            type Item = PathBuf;
             #[inline]
             fn clear(&mut self) {
                 self.region.clear();
-                self.inner.clear();
+            }
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
             }
             #[inline(always)]
-            unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
-                // TODO: Some types `T` should just be cloned, with `copy_slice`.
-                // E.g. types that are `Copy` or vecs of ZSTs.
-                let inner = &mut self.inner;
-                let elements = item.iter().map(|element| inner.copy(element));
-                if item.len() <= item.inline_size() {
-                    // The resulting SmallVec should not spill, so we avoid `from_iter` and friends
-                    // because they try to use the iterator's `size_hint` method to right-size the
-                    // capacity. If the hint is too large we could end up accidentally spilling.
-                    let mut item_new = SmallVec::new();
-                    for element in elements {
-                        item_new.push(element);
-                    }
-                    assert!(!item_new.spilled());
-                    item_new
-                } else {
-                    let slice = self.region.copy_iter(elements);
-                    SmallVec::from_raw_parts(slice.as_mut_ptr(), item.len(), item.len())
-                }
+            unsafe fn copy(&mut self, item: &PathBuf) -> PathBuf {
+                PathBuf::from(self.region.copy_os_str(item.as_os_str()))
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.as_os_str().len()
             }
             #[inline(always)]
             fn reserve_items<'a, I>(&mut self, items: I)
@@ -740,8 +5358,7 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item=&'a Self::Item>+Clone,
             {
-                self.region.reserve(items.clone().count());
-                self.inner.reserve_items(items.filter(|x| x.spilled()).flat_map(|x| x.iter()));
+                self.region.region.reserve(items.map(|x| x.as_os_str().len()).sum());
             }
 
             fn reserve_regions<'a, I>(&mut self, regions: I)
@@ -749,46 +5366,106 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item = &'a Self> + Clone,
             {
-                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
-                self.inner.reserve_regions(regions.map(|r| &r.inner));
+                self.region.reserve_regions(regions.map(|r| &r.region));
             }
             #[inline]
-            fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
-                self.inner.heap_size(&mut callback);
-                self.region.heap_size(callback);
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".path_buf.bytes", |path| {
+                    let path = &*path;
+                    self.region.region.heap_size(|used, capacity| callback(path, used, capacity));
+                });
+            }
+            #[inline]
+            fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+                self.region.heap_size(callback)
+            }
+        }
+
+        #[cfg(unix)]
+        impl RegionCapacity for PathBufStack {
+            type Capacity = usize;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                item.as_os_str().len()
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.measure()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve_capacity(capacity);
             }
         }
+
+        #[cfg(not(unix))]
+        impl Columnation for OsString {
+            type InnerRegion = CloneRegion<OsString>;
+        }
+
+        #[cfg(not(unix))]
+        impl Columnation for PathBuf {
+            type InnerRegion = CloneRegion<PathBuf>;
+        }
     }
 
-    /// Implementation for `String`.
-    pub mod string {
+    /// Implementation for `CString`.
+    pub mod c_string {
 
-        use super::{Columnation, Region, StableRegion};
+        use std::ffi::CString;
 
-        /// Region allocation for `String` data.
+        use super::{Columnation, Region, RegionCapacity, StableRegion, with_segment};
+
+        /// Region allocation for `CString` data.
         ///
-        /// Content bytes are stored in stable contiguous memory locations,
-        /// and then a `String` referencing them is falsified.
+        /// Content bytes, including the nul terminator, are stored in stable
+        /// contiguous memory locations, and then a `CString` referencing them is falsified.
         #[derive(Default)]
-        pub struct StringStack {
+        pub struct CStringStack {
             region: StableRegion<u8>,
         }
 
-        impl Columnation for String {
-            type InnerRegion = StringStack;
+        impl Columnation for CString {
+            type InnerRegion = CStringStack;
         }
 
-        impl Region for StringStack {
-            type Item = String;
+        impl Region for CStringStack {
+            type Item = CString;
             #[inline]
             fn clear(&mut self) {
                 self.region.clear();
             }
-            // Removing `(always)` is a 20% performance regression in
-            // the `string10_copy` benchmark.
-            #[inline(always)] unsafe fn copy(&mut self, item: &String) -> String {
-                let bytes = self.region.copy_slice(item.as_bytes());
-                String::from_raw_parts(bytes.as_mut_ptr(), item.len(), item.len())
+            #[inline]
+            fn clear_retaining(&mut self) {
+                self.region.clear_retaining();
+            }
+            #[inline]
+            fn stashed_chunks(&self) -> usize {
+                self.region.stashed_chunks()
+            }
+            #[cfg(feature = "hooks")]
+            #[inline]
+            fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                self.region.set_hook(hook);
+            }
+            #[inline]
+            fn checkpoint(&self, marks: &mut Vec<usize>) {
+                self.region.checkpoint(marks);
+            }
+            #[inline]
+            unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                self.region.rollback(marks);
+            }
+            #[inline(always)]
+            unsafe fn copy(&mut self, item: &CString) -> CString {
+                let item = item.as_bytes_with_nul();
+                let bytes = self.region.copy_slice(item);
+                // The vector's length and capacity agree, and so it is not reallocated.
+                CString::from_vec_with_nul_unchecked(Vec::from_raw_parts(bytes.as_mut_ptr(), item.len(), item.len()))
+            }
+            #[inline(always)]
+            fn item_heap_size(item: &Self::Item) -> usize {
+                item.as_bytes_with_nul().len()
             }
             #[inline(always)]
             fn reserve_items<'a, I>(&mut self, items: I)
@@ -796,7 +5473,7 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item=&'a Self::Item>+Clone,
             {
-                self.region.reserve(items.map(|x| x.len()).sum());
+                self.region.reserve(items.map(|x| x.as_bytes_with_nul().len()).sum());
             }
 
             fn reserve_regions<'a, I>(&mut self, regions: I)
@@ -804,19 +5481,42 @@ mod implementations {
                 Self: 'a,
                 I: Iterator<Item = &'a Self> + Clone,
             {
-                self.region.reserve(regions.clone().map(|r| r.region.len()).sum());
+                self.region.reserve(regions.map(|r| r.region.len()).sum());
+            }
+            #[inline]
+            fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                with_segment(path, ".c_string.bytes", |path| {
+                    let path = &*path;
+                    self.region.heap_size(|used, capacity| callback(path, used, capacity));
+                });
             }
             #[inline]
             fn heap_size(&self, callback: impl FnMut(usize, usize)) {
                 self.region.heap_size(callback)
             }
         }
+
+        impl RegionCapacity for CStringStack {
+            type Capacity = usize;
+            #[inline]
+            fn measure_item(item: &Self::Item) -> Self::Capacity {
+                item.as_bytes_with_nul().len()
+            }
+            #[inline]
+            fn measure(&self) -> Self::Capacity {
+                self.region.len()
+            }
+            #[inline]
+            fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                self.region.reserve(*capacity);
+            }
+        }
     }
 
     /// Implementation for tuples.
     pub mod tuple {
 
-        use super::{Columnation, ColumnStack, Region};
+        use super::{CapacityPlan, Columnation, ColumnStack, ChunkedColumnStack, Region, RegionCapacity, with_segment, TrivialRegion};
 
         use paste::paste;
 
@@ -843,6 +5543,18 @@ mod implementations {
             ([], [$(($index:tt))*], $self:ident, $regions:ident) => ( );
         }
 
+        // Reports the allocations of each constituent region at the path of its tuple index.
+        macro_rules! tuple_columnation_inner3 {
+            ([$name0:tt $($name:tt)*], [($index0:tt) $(($index:tt))*], $self:tt, $path:tt, $callback:tt) => ( paste! {
+                    with_segment($path, concat!(".", stringify!($index0)), |path| {
+                        $self.[<region $name0>].heap_size_paths(path, &mut $callback)
+                    });
+                    tuple_columnation_inner3!([$($name)*], [$(($index))*], $self, $path, $callback);
+                }
+            );
+            ([], [$(($index:tt))*], $self:ident, $path:ident, $callback:ident) => ( );
+        }
+
         /// The macro creates the region implementation for tuples
         macro_rules! tuple_columnation {
             ( $($name:ident)+) => ( paste! {
@@ -856,6 +5568,23 @@ mod implementations {
                     $([<region $name>]: $name),*
                 }
 
+                #[allow(non_snake_case)]
+                #[derive(Default, Clone, Debug)]
+                pub struct [<Tuple $($name)* Capacity >]<$($name),*> {
+                    $([<capacity $name>]: $name),*
+                }
+
+                impl<$($name: CapacityPlan),*> CapacityPlan for [<Tuple $($name)* Capacity>]<$($name),*> {
+                    #[inline]
+                    fn add(&mut self, other: &Self) {
+                        $(self.[<capacity $name>].add(&other.[<capacity $name>]);)*
+                    }
+                    #[inline]
+                    fn scale(&mut self, factor: f64) {
+                        $(self.[<capacity $name>].scale(factor);)*
+                    }
+                }
+
                 #[allow(non_snake_case)]
                 impl<$($name: Region),*> [<Tuple $($name)* Region>]<$($name),*> {
                     #[allow(clippy::too_many_arguments)]
@@ -869,10 +5598,32 @@ mod implementations {
                 #[allow(non_snake_case)]
                 impl<$($name: Region),*> Region for [<Tuple $($name)* Region>]<$($name),*> {
                     type Item = ($($name::Item,)*);
+                    const SHARES_MEMORY: bool = false $(|| $name::SHARES_MEMORY)*;
                     #[inline]
                     fn clear(&mut self) {
                         $(self.[<region $name>].clear());*
                     }
+                    #[inline]
+                    fn clear_retaining(&mut self) {
+                        $(self.[<region $name>].clear_retaining());*
+                    }
+                    #[inline]
+                    fn stashed_chunks(&self) -> usize {
+                        0 $(+ self.[<region $name>].stashed_chunks())*
+                    }
+                    #[cfg(feature = "hooks")]
+                    #[inline]
+                    fn set_hook(&mut self, hook: Option<&crate::RegionHook>) {
+                        $(self.[<region $name>].set_hook(hook);)*
+                    }
+                    #[inline]
+                    fn checkpoint(&self, marks: &mut Vec<usize>) {
+                        $(self.[<region $name>].checkpoint(marks);)*
+                    }
+                    #[inline]
+                    unsafe fn rollback(&mut self, marks: &mut std::slice::Iter<'_, usize>) {
+                        $(self.[<region $name>].rollback(marks);)*
+                    }
                     #[inline] unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
                         let ($(ref $name,)*) = *item;
                         (
@@ -880,6 +5631,11 @@ mod implementations {
                         )
                     }
                     #[inline(always)]
+                    fn item_heap_size(item: &Self::Item) -> usize {
+                        let ($(ref $name,)*) = *item;
+                        0 $(+ $name::item_heap_size($name))*
+                    }
+                    #[inline(always)]
                     fn reserve_items<'a, It>(&mut self, items: It)
                     where
                         Self: 'a,
@@ -896,13 +5652,232 @@ mod implementations {
                     {
                         tuple_columnation_inner2!([$($name)+], [(0) (1) (2) (3) (4) (5) (6) (7) (8) (9) (10) (11) (12) (13) (14) (15) (16) (17) (18) (19) (20) (21) (22) (23) (24) (25) (26) (27) (28) (29) (30) (31)], self, regions);
                     }
+                    #[inline]
+                    fn heap_size_paths(&self, path: &mut String, mut callback: impl FnMut(&str, usize, usize)) {
+                        tuple_columnation_inner3!([$($name)+], [(0) (1) (2) (3) (4) (5) (6) (7) (8) (9) (10) (11) (12) (13) (14) (15) (16) (17) (18) (19) (20) (21) (22) (23) (24) (25) (26) (27) (28) (29) (30) (31)], self, path, callback);
+                    }
                     #[inline] fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
                         $(self.[<region $name>].heap_size(&mut callback);)*
                     }
                 }
+
+                #[allow(non_snake_case)]
+                impl<$($name: RegionCapacity),*> RegionCapacity for [<Tuple $($name)* Region>]<$($name),*> {
+                    type Capacity = [<Tuple $($name)* Capacity>]<$($name::Capacity),*>;
+                    #[inline]
+                    fn measure_item(item: &Self::Item) -> Self::Capacity {
+                        let ($(ref $name,)*) = *item;
+                        [<Tuple $($name)* Capacity>] {
+                            $([<capacity $name>]: $name::measure_item($name),)*
+                        }
+                    }
+                    #[inline]
+                    fn measure(&self) -> Self::Capacity {
+                        [<Tuple $($name)* Capacity>] {
+                            $([<capacity $name>]: self.[<region $name>].measure(),)*
+                        }
+                    }
+                    #[inline]
+                    fn reserve_capacity(&mut self, capacity: &Self::Capacity) {
+                        $(self.[<region $name>].reserve_capacity(&capacity.[<capacity $name>]);)*
+                    }
+                }
+
+                #[allow(non_snake_case)]
+                unsafe impl<$($name: TrivialRegion),*> TrivialRegion for [<Tuple $($name)* Region>]<$($name),*> { }
                 }
                 tuple_column_stack!(ColumnStack, $($name)*);
+                tuple_column_stack!(ChunkedColumnStack, $($name)*);
+                tuple_columns!($($name)*);
+                tuple_columns_index!([$($name)+], [$($name)+], [(0) (1) (2) (3) (4) (5) (6) (7) (8) (9) (10) (11) (12) (13) (14) (15) (16) (17) (18) (19) (20) (21) (22) (23) (24) (25) (26) (27) (28) (29) (30) (31)]);
+            );
+        }
+
+        /// A tuple type whose components can be stored in separate columns.
+        pub trait TupleColumnation: Columnation {
+            /// A tuple of one [ColumnStack] per component.
+            type Columns;
+        }
+
+        /// A container of tuples that stores each component in its own [ColumnStack].
+        ///
+        /// Each component has its own spine and region, so that a scan of one component
+        /// only touches that component's memory. Components are available together by
+        /// index with `get`, or as whole columns with `column`.
+        pub struct TupleColumns<T: TupleColumnation> {
+            columns: T::Columns,
+        }
+
+        /// Access to the `I`-th column of a [TupleColumns].
+        pub trait ColumnIndex<const I: usize> {
+            /// The type of the `I`-th tuple component.
+            type Item: Columnation;
+            /// The `I`-th components of all tuples.
+            fn column(&self) -> &[Self::Item];
+            /// Mutable access to the `I`-th components of all tuples, for components whose region is trivial.
+            fn column_mut(&mut self) -> &mut [Self::Item]
+            where
+                <Self::Item as Columnation>::InnerRegion: TrivialRegion;
+        }
+
+        /// Access to the `I`-th component of a tuple.
+        pub trait TupleIndex<const I: usize> {
+            /// The type of the `I`-th component.
+            type Item;
+            /// The `I`-th component.
+            fn index(&self) -> &Self::Item;
+            /// Mutable access to the `I`-th component.
+            fn index_mut(&mut self) -> &mut Self::Item;
+        }
+
+        impl<T: TupleColumnation> TupleColumns<T> {
+            /// The `I`-th components of all tuples.
+            #[inline]
+            pub fn column<const I: usize>(&self) -> &[<Self as ColumnIndex<I>>::Item]
+            where
+                Self: ColumnIndex<I>,
+            {
+                <Self as ColumnIndex<I>>::column(self)
+            }
+            /// Mutable access to the `I`-th components of all tuples, for components whose region is trivial.
+            #[inline]
+            pub fn column_mut<const I: usize>(&mut self) -> &mut [<Self as ColumnIndex<I>>::Item]
+            where
+                Self: ColumnIndex<I>,
+                <<Self as ColumnIndex<I>>::Item as Columnation>::InnerRegion: TrivialRegion,
+            {
+                <Self as ColumnIndex<I>>::column_mut(self)
+            }
+        }
+
+        macro_rules! tuple_columns_inner {
+            ([$name0:tt $($name:tt)*], [($index0:tt) $(($index:tt))*], $self:tt, $items:tt) => (
+                $self.columns.$index0.reserve_items($items.clone().map(|item| &item.$index0));
+                tuple_columns_inner!([$($name)*], [$(($index))*], $self, $items);
+            );
+            ([], [$(($index:tt))*], $self:ident, $items:ident) => ( );
+        }
+
+        macro_rules! tuple_columns_index {
+            ([$($all:ident)+], [$name0:ident $($name:ident)*], [($index0:tt) $(($index:tt))*]) => (
+                impl<$($all: Columnation),+> ColumnIndex<$index0> for TupleColumns<($($all,)+)> {
+                    type Item = $name0;
+                    #[inline]
+                    fn column(&self) -> &[$name0] {
+                        &self.columns.$index0[..]
+                    }
+                    #[inline]
+                    fn column_mut(&mut self) -> &mut [$name0]
+                    where
+                        $name0::InnerRegion: TrivialRegion,
+                    {
+                        self.columns.$index0.as_mut_slice()
+                    }
+                }
+                impl<$($all),+> TupleIndex<$index0> for ($($all,)+) {
+                    type Item = $name0;
+                    #[inline]
+                    fn index(&self) -> &$name0 {
+                        &self.$index0
+                    }
+                    #[inline]
+                    fn index_mut(&mut self) -> &mut $name0 {
+                        &mut self.$index0
+                    }
+                }
+                tuple_columns_index!([$($all)+], [$($name)*], [$(($index))*]);
             );
+            ([$($all:ident)+], [], [$(($index:tt))*]) => ( );
+        }
+
+        /// The macro creates the `TupleColumns` implementation for a tuple type.
+        macro_rules! tuple_columns {
+            ( $($name:ident)+) => ( paste! {
+                impl<$($name: Columnation),*> TupleColumnation for ($($name,)*) {
+                    type Columns = ($(ColumnStack<$name>,)*);
+                }
+
+                // Manually implement `Default` as tuples implement it only up to a limited arity.
+                impl<$($name: Columnation),*> Default for TupleColumns<($($name,)*)> {
+                    fn default() -> Self {
+                        Self { columns: ($(ColumnStack::<$name>::default(),)*) }
+                    }
+                }
+
+                #[allow(non_snake_case)]
+                impl<$($name: Columnation),*> TupleColumns<($($name,)*)> {
+                    /// Copies a tuple in to the columns.
+                    #[inline]
+                    pub fn copy(&mut self, item: &($($name,)*)) {
+                        let ($(ref $name,)*) = *item;
+                        self.copy_destructured($($name,)*);
+                    }
+                    /// Copies a destructured tuple in to the columns.
+                    ///
+                    /// This serves situations where a tuple should be constructed from its constituents but
+                    /// not all elements are available as owned data.
+                    #[allow(clippy::too_many_arguments)]
+                    #[inline]
+                    pub fn copy_destructured(&mut self, $($name: &$name,)*) {
+                        let ($([<column $name>],)*) = &mut self.columns;
+                        $([<column $name>].copy($name);)*
+                    }
+                    /// References to the components of the tuple at `index`.
+                    ///
+                    /// Panics if `index` is out of bounds.
+                    #[inline]
+                    pub fn get(&self, index: usize) -> ($(&$name,)*) {
+                        let ($([<column $name>],)*) = &self.columns;
+                        ($(&[<column $name>][index],)*)
+                    }
+                    /// Iterates over references to the components of each tuple.
+                    pub fn iter(&self) -> impl ExactSizeIterator<Item = ($(&$name,)*)> + '_ {
+                        (0 .. self.len()).map(move |index| self.get(index))
+                    }
+                    /// The number of tuples in the columns.
+                    #[inline]
+                    pub fn len(&self) -> usize {
+                        self.columns.0.len()
+                    }
+                    /// Returns `true` if the columns hold no tuples.
+                    #[inline]
+                    pub fn is_empty(&self) -> bool {
+                        self.len() == 0
+                    }
+                    /// Empties the collection.
+                    pub fn clear(&mut self) {
+                        let ($([<column $name>],)*) = &mut self.columns;
+                        $([<column $name>].clear();)*
+                    }
+                    /// Ensures `Self` can absorb `items` without further allocations.
+                    ///
+                    /// The argument `items` may be cloned and iterated multiple times.
+                    /// Please be careful if it contains side effects.
+                    pub fn reserve_items<'a, It>(&mut self, items: It)
+                    where
+                        $($name: 'a,)*
+                        It: Iterator<Item = &'a ($($name,)*)> + Clone,
+                    {
+                        tuple_columns_inner!([$($name)+], [(0) (1) (2) (3) (4) (5) (6) (7) (8) (9) (10) (11) (12) (13) (14) (15) (16) (17) (18) (19) (20) (21) (22) (23) (24) (25) (26) (27) (28) (29) (30) (31)], self, items);
+                    }
+                    /// Estimate the memory capacity in bytes.
+                    #[inline]
+                    pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+                        let ($([<column $name>],)*) = &self.columns;
+                        $([<column $name>].heap_size(&mut callback);)*
+                    }
+                    /// Estimate the consumed memory capacity in bytes, summing both used and total capacity.
+                    #[inline]
+                    pub fn summed_heap_size(&self) -> (usize, usize) {
+                        let (mut length, mut capacity) = (0, 0);
+                        self.heap_size(|len, cap| {
+                            length += len;
+                            capacity += cap
+                        });
+                        (length, capacity)
+                    }
+                }
+            });
         }
 
         /// The macro creates the `copy_destructured` implementation for a custom column stack
@@ -910,6 +5885,7 @@ mod implementations {
         /// It assumes there are two fields on `self`:
         /// * `local`: A type supporting `push(T)`, e.g, `Vec`.
         /// * `inner`: A region of type `T`.
+        ///
         /// We're exporting this macro so custom `ColumnStack` implementations can benefit from it.
         #[macro_export]
         macro_rules! tuple_column_stack {
@@ -922,6 +5898,7 @@ mod implementations {
                     /// not all elements are available as owned data.
                     ///
                     /// The element can be read by indexing
+                    #[allow(clippy::too_many_arguments)]
                     pub fn copy_destructured(&mut self, $($name: &$name,)*) {
                         unsafe {
                             self.local.push(self.inner.copy_destructured($($name,)*));
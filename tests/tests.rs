@@ -4,8 +4,8 @@ use columnation::*;
 #[test] fn test_option_vec() { _test_pass(vec![Some(vec![0, 1, 2])]); }
 #[test] fn test_u32x3_pass() { _test_pass(vec![((1,2,3),vec![(0u32, 0u32, 0u32); 1024])]); }
 #[test] fn test_u64_pass() { _test_pass(vec![0u64; 1024]); }
-#[test] fn test_string_pass() { _test_pass(vec![format!("grawwwwrr!"); 1024]); }
-#[test] fn test_vec_u_s_pass() { _test_pass(vec![vec![(0u64, format!("grawwwwrr!")); 32]; 32]); }
+#[test] fn test_string_pass() { _test_pass(vec!["grawwwwrr!".to_string(); 1024]); }
+#[test] fn test_vec_u_s_pass() { _test_pass(vec![vec![(0u64, "grawwwwrr!".to_string()); 32]; 32]); }
 #[test]
 fn test_smallvec() {
     use smallvec::SmallVec;
@@ -15,6 +15,28 @@ fn test_smallvec() {
     _test_pass(v);
 }
 
+#[test]
+fn test_smallvec_reserve() {
+    use smallvec::SmallVec;
+    // Inline items, an item that spilled but whose copy fits inline, and items whose copies spill.
+    let mut records: Vec<SmallVec<[String; 2]>> = Vec::new();
+    records.push(SmallVec::from_vec(vec!["a".to_string()]));
+    let mut spilled = SmallVec::with_capacity(8);
+    spilled.push("bb".to_string());
+    records.push(spilled);
+    records.push(SmallVec::from_vec(vec!["ccc".to_string(); 3]));
+    records.push(SmallVec::from_vec(vec!["dddd".to_string(); 5]));
+
+    let mut arena = ColumnStack::default();
+    arena.reserve_items(records.iter());
+    let (_, capacity) = arena.summed_heap_size();
+    for record in records.iter() {
+        arena.copy(record);
+    }
+    assert_eq!(arena.summed_heap_size(), (capacity, capacity));
+    assert!(arena.iter().eq(records.iter()));
+}
+
 fn _test_pass<T: Columnation+Eq>(record: T) {
 
     // prepare encoded data for bencher.bytes
@@ -26,3 +48,883 @@ fn _test_pass<T: Columnation+Eq>(record: T) {
         assert!(element == &record);
     }
 }
+
+#[test]
+fn test_clone_region() {
+    use std::collections::{BTreeMap, HashMap};
+    use std::rc::Rc;
+    let map: HashMap<u64, String> = (0 .. 10).map(|i| (i, i.to_string())).collect();
+    _test_pass(("grawwwwrr!".to_string(), map));
+    _test_pass(Cloned(BTreeMap::from([(0u8, vec![1, 2, 3])])));
+
+    // Clones must be released once the stack is cleared or dropped.
+    let record = Rc::new("grawwwwrr!".to_string());
+    let mut arena = ColumnStack::<Rc<String>>::default();
+    for _ in 0 .. 100 {
+        arena.copy(&record);
+    }
+    assert_eq!(Rc::strong_count(&record), 101);
+    arena.clear();
+    assert_eq!(Rc::strong_count(&record), 1);
+    arena.copy(&record);
+    drop(arena);
+    assert_eq!(Rc::strong_count(&record), 1);
+
+    // Interior mutability behind an owned allocation is shared by the alias and its clone.
+    let mut arena = ColumnStack::<Cloned<Vec<std::cell::RefCell<String>>>>::default();
+    arena.copy(&Cloned(vec![std::cell::RefCell::new("grawwwwrr!".to_string())]));
+    *arena[0][0].borrow_mut() = String::new();
+    assert!(arena[0][0].borrow().is_empty());
+    arena.clear();
+}
+
+#[test]
+fn test_interning_region() {
+    let codes = ["us", "de", "fr"];
+    let mut arena = ColumnStack::<Interned<String>>::default();
+    for index in 0 .. 1000 {
+        arena.copy(&Interned(codes[index % 3].to_string()));
+    }
+    for (index, element) in arena.iter().enumerate() {
+        assert_eq!(element.as_str(), codes[index % 3]);
+    }
+    assert_eq!(arena.region().misses(), 3);
+    assert_eq!(arena.region().hits(), 997);
+    _test_pass((Interned(vec![1u32, 2, 3]), Interned("grawwwwrr!".to_string())));
+
+    // Reserving for items sizes the table, which then does not grow.
+    let items: Vec<_> = (0 .. 1000).map(|index| Interned(index.to_string())).collect();
+    let mut arena = ColumnStack::<Interned<String>>::default();
+    arena.reserve_items(items.iter());
+    let reserved = arena.heap_report().get(".interning.table").unwrap().reserved;
+    for item in items.iter() {
+        arena.copy(item);
+    }
+    assert!(reserved > 0);
+    assert_eq!(arena.heap_report().get(".interning.table").unwrap().reserved, reserved);
+}
+
+#[test]
+fn test_interning_garbage() {
+    // Interned items share memory, and so removing them reclaims nothing.
+    const _: () = assert!(<<(u64, Interned<String>) as Columnation>::InnerRegion as Region>::SHARES_MEMORY);
+    const _: () = assert!(!<<(u64, String) as Columnation>::InnerRegion as Region>::SHARES_MEMORY);
+
+    let mut arena = ColumnStack::<(u64, Interned<String>)>::default();
+    arena.set_compact_fraction(Some(0.1));
+    for index in 0 .. 100u64 {
+        arena.copy(&(index, Interned("grawwwwrr!".to_string())));
+    }
+    for index in 0 .. 100 {
+        arena.replace(index, &(0, Interned("grawwwwrr!".to_string())));
+    }
+    arena.truncate(50);
+    assert_eq!(arena.garbage_bytes(), 0);
+    assert_eq!(arena.stats().dead_bytes, 0);
+
+    let mut slab = ColumnSlab::<Interned<String>>::default();
+    slab.set_compact_fraction(Some(0.1));
+    let ids: Vec<usize> = (0 .. 100).map(|_| slab.insert(&Interned("grawwwwrr!".to_string()))).collect();
+    for id in ids {
+        slab.remove(id);
+    }
+    assert_eq!(slab.dead_bytes(), 0);
+}
+
+#[test] fn test_box_pass() { _test_pass(Box::new((0u64, "grawwwwrr!".to_string()))); }
+#[test] fn test_box_slice_pass() { _test_pass(vec!["grawwwwrr!".to_string(); 32].into_boxed_slice()); }
+#[test] fn test_box_str_pass() { _test_pass(Box::<str>::from("grawwwwrr!")); }
+#[test]
+fn test_cow_pass() {
+    use std::borrow::Cow;
+    _test_pass((Cow::Borrowed("grawwwwrr!"), Cow::<str>::Owned("grawwwwrr!".to_string())));
+    _test_pass(Cow::<[Vec<u8>]>::Owned(vec![vec![0, 1, 2]; 32]));
+}
+
+#[test]
+fn test_vec_deque_pass() {
+    use std::collections::VecDeque;
+    let mut deque: VecDeque<String> = (0 .. 10).map(|i| i.to_string()).collect();
+    // Rotate so that the deque's storage wraps around.
+    deque.rotate_left(3);
+    deque.push_front("grawwwwrr!".to_string());
+    _test_pass(deque);
+}
+#[test]
+fn test_binary_heap() {
+    use std::collections::BinaryHeap;
+    let heap: BinaryHeap<(u64, String)> = (0 .. 10).map(|i| (i % 4, i.to_string())).collect();
+    let mut arena = ColumnStack::<BinaryHeap<(u64, String)>>::default();
+    for _ in 0 .. 100 {
+        arena.copy(&heap);
+    }
+    for element in arena.iter() {
+        assert_eq!(element.clone().into_sorted_vec(), heap.clone().into_sorted_vec());
+        assert_eq!(element.peek(), heap.peek());
+    }
+
+    // A panicking comparison must not release region memory while unwinding.
+    use std::cell::Cell;
+    thread_local!(static PANIC: Cell<bool> = const { Cell::new(false) });
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    struct Panicky(u64);
+    impl PartialOrd for Panicky {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Panicky {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            assert!(!PANIC.with(|panic| panic.get()), "comparison panicked");
+            self.0.cmp(&other.0)
+        }
+    }
+    impl Columnation for Panicky {
+        type InnerRegion = CopyRegion<Panicky>;
+    }
+    let heap: BinaryHeap<Panicky> = (0 .. 10).map(Panicky).collect();
+    let mut arena = ColumnStack::<BinaryHeap<Panicky>>::default();
+    arena.copy(&heap);
+    PANIC.with(|panic| panic.set(true));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| arena.copy(&heap)));
+    PANIC.with(|panic| panic.set(false));
+    assert!(result.is_err());
+    arena.copy(&heap);
+    assert_eq!(arena.len(), 2);
+    assert_eq!(arena[1].peek(), Some(&Panicky(9)));
+    drop(arena);
+}
+
+#[test] fn test_array_u64_pass() { _test_pass([0u64, 1, 2, 3]); }
+#[test] fn test_array_string_pass() { _test_pass(["grawwwwrr!".to_string(), String::new(), "a".to_string(), "b".to_string()]); }
+#[test] fn test_array_vec_pass() { _test_pass([vec![0u8, 1, 2], vec![]]); }
+
+#[test]
+fn test_std_copy_pass() {
+    use std::num::*;
+    _test_pass((Wrapping(1u8), Wrapping(2u64), Wrapping(-3i32)));
+    let _: CopyRegion<Wrapping<i32>> = <Wrapping<i32> as Columnation>::InnerRegion::default();
+    #[cfg(feature = "saturating")]
+    _test_pass((Saturating(4u16), Saturating(-5i128)));
+    _test_pass((NonZeroU8::new(1).unwrap(), NonZeroU128::new(2).unwrap(), NonZeroUsize::new(3).unwrap()));
+    _test_pass((NonZeroI8::new(-1).unwrap(), NonZeroI64::new(2).unwrap(), NonZeroIsize::new(-3).unwrap()));
+    _test_pass((FpCategory::Normal, std::cmp::Ordering::Less));
+
+    use std::net::*;
+    let v4 = Ipv4Addr::new(127, 0, 0, 1);
+    let v6 = Ipv6Addr::LOCALHOST;
+    _test_pass((IpAddr::V4(v4), v4, v6, SocketAddr::new(IpAddr::V6(v6), 8080)));
+    _test_pass((SocketAddrV4::new(v4, 80), SocketAddrV6::new(v6, 443, 0, 0)));
+
+    _test_pass((std::time::Duration::from_secs(1), std::time::Instant::now(), std::time::SystemTime::now()));
+    _test_pass((std::any::TypeId::of::<String>(), std::marker::PhantomData::<String>));
+}
+
+#[test]
+fn test_wrapper_pass() {
+    use std::cmp::Reverse;
+    use std::mem::ManuallyDrop;
+    use std::ops::Bound;
+    _test_pass((Reverse("grawwwwrr!".to_string()), Reverse(vec![1u8, 2, 3])));
+    _test_pass(vec![ManuallyDrop::new("grawwwwrr!".to_string()); 4]);
+    _test_pass(("a".to_string() .. "z".to_string(), vec![0u8] ..= vec![1u8], 0u64 .. 10));
+    _test_pass(vec![Bound::Included("a".to_string()), Bound::Excluded("z".to_string()), Bound::Unbounded]);
+}
+
+#[test]
+fn test_os_string_pass() {
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+    _test_pass(OsString::from("grawwwwrr!"));
+    _test_pass((PathBuf::from("/usr/lib/grawwwwrr.so"), PathBuf::new()));
+}
+#[test]
+fn test_c_string_pass() {
+    use std::ffi::CString;
+    let record = CString::new("grawwwwrr!").unwrap();
+    _test_pass(record.clone());
+    let mut arena = ColumnStack::<CString>::default();
+    arena.copy(&record);
+    assert_eq!(arena[0].as_bytes_with_nul(), b"grawwwwrr!\0");
+}
+
+#[test]
+fn test_slice_stack() {
+    let records = [vec![0u32, 1, 2], vec![], vec![3; 1024]];
+    let mut stack = SliceStack::<u32>::default();
+    stack.reserve_items(records.iter().map(|x| &x[..]));
+    for _ in 0 .. 100 {
+        stack.extend(records.iter().map(|x| &x[..]));
+    }
+    assert_eq!(stack.len(), 300);
+    for (index, element) in stack.iter().enumerate() {
+        assert_eq!(element, &records[index % 3][..]);
+    }
+    assert_eq!(stack.get(300), None);
+    assert_eq!(stack.clone(), stack);
+    stack.clear();
+    assert!(stack.is_empty());
+
+    // The stack spends one word per record, besides per-allocation bookkeeping.
+    let stack: SliceStack<u8> = (0 .. 1000).map(|_| &b"grawwwwrr!"[..]).collect();
+    assert!(stack.iter().all(|slice| slice == b"grawwwwrr!"));
+    let (used, _) = stack.summed_heap_size();
+    assert!(used <= 1000 * (std::mem::size_of::<usize>() + 10) + 64 * std::mem::size_of::<Vec<u8>>());
+
+    // Elements are owned by the stack, and dropped once it is cleared or dropped.
+    let element = std::rc::Rc::new(0u32);
+    let mut stack = SliceStack::default();
+    for len in 0 .. 100 {
+        stack.copy(&vec![element.clone(); len]);
+    }
+    assert_eq!(std::rc::Rc::strong_count(&element), 1 + 99 * 100 / 2);
+    assert!(stack.iter().enumerate().all(|(len, slice)| slice.len() == len));
+    stack.clear();
+    assert_eq!(std::rc::Rc::strong_count(&element), 1);
+    stack.copy(std::slice::from_ref(&element));
+    drop(stack);
+    assert_eq!(std::rc::Rc::strong_count(&element), 1);
+
+    let mut stack = StrStack::default();
+    stack.copy("grawwwwrr!");
+    stack.copy("");
+    assert_eq!(&stack[0], "grawwwwrr!");
+    assert_eq!(stack.iter().collect::<Vec<_>>(), vec!["grawwwwrr!", ""]);
+}
+
+#[test]
+fn test_tuple_columns() {
+    let mut columns = TupleColumns::<(u64, String, Vec<u32>)>::default();
+    let records: Vec<_> = (0 .. 100u64).map(|i| (i, i.to_string(), vec![i as u32; 3])).collect();
+    columns.reserve_items(records.iter());
+    let reserved = |columns: &TupleColumns<(u64, String, Vec<u32>)>| {
+        let mut reserved = 0;
+        columns.heap_size(|_, cap| reserved += cap);
+        reserved
+    };
+    let before = reserved(&columns);
+    for record in records.iter() {
+        columns.copy(record);
+    }
+    // Reserving for the records leaves nothing to allocate while copying them.
+    assert_eq!(reserved(&columns), before);
+    columns.copy_destructured(&100, &"100".to_string(), &vec![]);
+    assert_eq!(columns.len(), 101);
+    assert_eq!(columns.column::<0>(), &(0 .. 101).collect::<Vec<_>>()[..]);
+    assert_eq!(columns.column::<1>()[7], "7");
+    assert_eq!(columns.get(3), (&3, &"3".to_string(), &vec![3u32; 3]));
+    assert_eq!(columns.iter().count(), 101);
+    columns.clear();
+    assert!(columns.is_empty());
+}
+
+#[test]
+fn test_chunked_column_stack() {
+    let mut arena = ChunkedColumnStack::<(u64, String)>::with_chunk_len(7);
+    for index in 0 .. 100u64 {
+        arena.copy(&(index, index.to_string()));
+    }
+    arena.copy_destructured(&100, &"100".to_string());
+    assert_eq!(arena.len(), 101);
+    assert_eq!(arena.as_slices().len(), 15);
+    assert!(arena.as_slices().all(|slice| slice.len() <= 7));
+    for (index, element) in arena.iter().enumerate() {
+        assert_eq!(element, &(index as u64, index.to_string()));
+        assert_eq!(&arena[index], element);
+    }
+    assert_eq!(arena.get(101), None);
+    assert_eq!(arena.clone(), arena);
+    arena.clear();
+    assert!(arena.is_empty());
+    arena.copy(&(0, "grawwwwrr!".to_string()));
+    assert_eq!(arena[0].1, "grawwwwrr!");
+}
+
+#[test]
+fn test_column_queue() {
+    let mut queue = ColumnQueue::<(u64, String)>::with_segment_len(10);
+    let mut popped = 0u64;
+    for index in 0 .. 1000u64 {
+        queue.copy(&(index, index.to_string()));
+        if index % 3 == 0 {
+            assert_eq!(queue.pop(), Some(&(popped, popped.to_string())));
+            popped += 1;
+        }
+    }
+    assert_eq!(queue.len(), 1000 - popped as usize);
+    assert_eq!(queue.front(), Some(&(popped, popped.to_string())));
+    assert!(queue.iter().map(|x| x.0).eq(popped .. 1000));
+    // Draining the queue reclaims all but a bounded number of segments.
+    let (_, capacity) = queue.summed_heap_size();
+    while let Some(record) = queue.pop() {
+        assert_eq!(record, &(popped, popped.to_string()));
+        popped += 1;
+    }
+    assert_eq!(popped, 1000);
+    assert!(queue.is_empty());
+    queue.copy(&(0, "grawwwwrr!".to_string()));
+    assert!(queue.summed_heap_size().1 < capacity);
+    queue.clear();
+    assert_eq!(queue.pop(), None);
+}
+
+#[test]
+fn test_column_slab() {
+    let mut slab = ColumnSlab::<(u64, String)>::default();
+    slab.set_compact_fraction(None);
+    let ids: Vec<usize> = (0 .. 100u64).map(|i| slab.insert(&(i, format!("grawwwwrr{}", i)))).collect();
+    assert_eq!(ids, (0 .. 100).collect::<Vec<_>>());
+    for id in (0 .. 100).step_by(2) {
+        assert!(slab.remove(id));
+    }
+    assert!(!slab.remove(0));
+    assert_eq!(slab.len(), 50);
+    assert_eq!(slab.get(0), None);
+    assert!(slab.dead_bytes() > 0);
+    let live = slab.live_bytes();
+
+    // Compaction reclaims dead bytes and keeps ids stable.
+    slab.compact();
+    assert_eq!(slab.dead_bytes(), 0);
+    assert_eq!(slab.live_bytes(), live);
+    for (id, record) in slab.iter() {
+        assert_eq!(record, &(id as u64, format!("grawwwwrr{}", id)));
+    }
+
+    // Vacant slots are reused.
+    let id = slab.insert(&(1000, "grawwwwrr!".to_string()));
+    assert!(id < 100 && id % 2 == 0);
+    assert_eq!(slab[id].1, "grawwwwrr!");
+
+    // Automatic compaction bounds dead bytes.
+    slab.set_compact_fraction(Some(0.25));
+    for id in (1 .. 100).step_by(2) {
+        slab.remove(id);
+        assert!(slab.dead_bytes() * 4 <= slab.dead_bytes() + slab.live_bytes());
+    }
+    assert_eq!(slab.len(), 1);
+}
+
+/// The bytes in use by the region of `arena`.
+fn region_bytes<T: Columnation>(arena: &ColumnStack<T>) -> usize {
+    let mut used = 0;
+    arena.region().heap_size(|len, _cap| used += len);
+    used
+}
+
+#[test]
+fn test_column_stack_replace() {
+    let mut arena = ColumnStack::<(u64, String)>::default();
+    arena.set_compact_fraction(None);
+    for index in 0 .. 100u64 {
+        arena.copy(&(index, index.to_string()));
+    }
+    arena.replace(3, &(3, "grawwwwrr!".to_string()));
+    arena.update_with(4, |(index, string)| (index + 1, format!("{}!", string)));
+    assert_eq!(arena[3], (3, "grawwwwrr!".to_string()));
+    assert_eq!(arena[4], (5, "4!".to_string()));
+    assert_eq!(arena.garbage_bytes(), 2);
+
+    let used = region_bytes(&arena);
+    arena.compact();
+    assert_eq!(arena.garbage_bytes(), 0);
+    assert!(region_bytes(&arena) < used);
+    assert_eq!(arena[3].1, "grawwwwrr!");
+
+    // Automatic compaction bounds garbage.
+    arena.set_compact_fraction(Some(0.1));
+    for index in 0 .. 1000 {
+        arena.replace(index % 100, &(0, "grawwwwrr!".to_string()));
+        assert!(arena.garbage_bytes() <= arena.summed_heap_size().0 / 10);
+    }
+    assert!(arena.iter().all(|x| x == &(0, "grawwwwrr!".to_string())));
+}
+
+#[test]
+fn test_column_mut() {
+    let mut arena = ColumnStack::<(String, u64, Option<i64>)>::default();
+    for index in 0 .. 100u64 {
+        arena.copy(&(index.to_string(), index, Some(-(index as i64))));
+    }
+    for diff in arena.column_mut::<1>() {
+        *diff += 1;
+    }
+    arena.column_mut::<2>().for_each(|x| *x = None);
+    for (index, element) in arena.iter().enumerate() {
+        assert_eq!(element, &(index.to_string(), index as u64 + 1, None));
+    }
+
+    let mut arena = ColumnStack::<u64>::default();
+    arena.extend(&[1, 2, 3]);
+    arena.as_mut_slice().reverse();
+    assert_eq!(&arena[..], &[3, 2, 1]);
+
+    let mut columns = TupleColumns::<(String, u64)>::default();
+    columns.copy(&("grawwwwrr!".to_string(), 0));
+    columns.column_mut::<1>()[0] = 1;
+    assert_eq!(columns.get(0), (&"grawwwwrr!".to_string(), &1));
+}
+
+#[test]
+fn test_column_stack_rollback() {
+    let mut arena = ColumnStack::<(u64, String, Vec<u64>)>::default();
+    for index in 0 .. 100u64 {
+        arena.copy(&(index, index.to_string(), vec![index; 4]));
+    }
+    let before = arena.summed_heap_size().0;
+    {
+        let mut checkpoint = arena.checkpoint();
+        for index in 0 .. 10000u64 {
+            checkpoint.copy(&(index, "grawwwwrr!".to_string(), vec![index; 8]));
+        }
+        {
+            let mut nested = checkpoint.checkpoint();
+            nested.copy(&(0, "grawwwwrr!".to_string(), vec![]));
+            nested.rollback();
+        }
+        assert_eq!(checkpoint.len(), 10100);
+
+        // Rolling back reclaims the region memory.
+        checkpoint.rollback();
+        assert_eq!(checkpoint.len(), 100);
+        assert!(checkpoint.summed_heap_size().0 <= before + 100 * 64);
+        for (index, element) in checkpoint.iter().enumerate() {
+            assert_eq!(element, &(index as u64, index.to_string(), vec![index as u64; 4]));
+        }
+
+        // Checkpoints remain valid after rollback.
+        checkpoint.copy(&(0, "grawwwwrr!".to_string(), vec![]));
+        checkpoint.rollback();
+        assert_eq!(checkpoint.len(), 100);
+        assert_eq!(checkpoint.garbage_bytes(), 0);
+    }
+
+    // Dropping a checkpoint keeps the elements added since.
+    {
+        let mut checkpoint = arena.checkpoint();
+        checkpoint.copy(&(0, "grawwwwrr!".to_string(), vec![]));
+    }
+    assert_eq!(arena.len(), 101);
+
+    // Checkpoints can be rolled back to after replacing or removing elements added since.
+    {
+        let mut checkpoint = arena.checkpoint();
+        checkpoint.copy(&(0, "grawwwwrr!".to_string(), vec![0; 8]));
+        checkpoint.copy(&(0, "grawwwwrr!".to_string(), vec![0; 8]));
+        checkpoint.replace(101, &(1, "1".to_string(), vec![1]));
+        checkpoint.truncate(102);
+        assert!(checkpoint.garbage_bytes() > 0);
+        checkpoint.rollback();
+    }
+    assert_eq!(arena.len(), 101);
+    assert_eq!(arena.garbage_bytes(), 0);
+
+    // After replacing an element before the checkpoint, rolling back counts garbage.
+    let used = region_bytes(&arena);
+    {
+        let mut checkpoint = arena.checkpoint();
+        checkpoint.copy(&(0, "grawwwwrr!".to_string(), vec![0; 8]));
+        checkpoint.replace(0, &(1, "1".to_string(), vec![1]));
+        checkpoint.rollback();
+    }
+    assert_eq!(arena.len(), 101);
+    assert_eq!(arena[0], (1, "1".to_string(), vec![1]));
+    assert!(arena.garbage_bytes() > 0);
+    assert!(region_bytes(&arena) > used);
+
+    // Compacting invalidates the position of the region at the checkpoint.
+    {
+        let mut checkpoint = arena.checkpoint();
+        checkpoint.copy(&(0, "grawwwwrr!".to_string(), vec![0; 8]));
+        checkpoint.compact();
+        checkpoint.rollback();
+    }
+    assert_eq!(arena.len(), 101);
+    assert_eq!(arena[100], (0, "grawwwwrr!".to_string(), vec![]));
+    assert!(arena.garbage_bytes() > 0);
+    arena.compact();
+
+    // Rolling back to an empty stack clears it.
+    arena.clear();
+    {
+        let mut checkpoint = arena.checkpoint();
+        checkpoint.copy(&(0, "grawwwwrr!".to_string(), vec![]));
+        checkpoint.rollback();
+    }
+    assert!(arena.is_empty());
+    assert_eq!(region_bytes(&arena), 0);
+
+    // Forgotten checkpoints do not prevent automatic compaction.
+    arena.set_compact_fraction(Some(0.5));
+    for index in 0 .. 100u64 {
+        arena.copy(&(index, index.to_string(), vec![index; 4]));
+    }
+    std::mem::forget(arena.checkpoint());
+    for _ in 0 .. 10 {
+        for index in 0 .. 100 {
+            arena.replace(index, &(0, "grawwwwrr!".to_string(), vec![0; 8]));
+        }
+    }
+    assert!(arena.garbage_bytes() <= arena.summed_heap_size().0 / 2);
+}
+
+#[test]
+fn test_column_stack_truncate() {
+    let mut arena = ColumnStack::<(u64, String, Vec<String>)>::default();
+    for index in 0 .. 100u64 {
+        arena.copy(&(index, index.to_string(), vec![index.to_string(); 2]));
+    }
+    let used = region_bytes(&arena);
+    for index in 0 .. 10000u64 {
+        arena.copy_destructured(&index, &"grawwwwrr!".to_string(), &vec!["grawwwwrr!".to_string(); 4]);
+    }
+
+    // Truncation counts the region bytes of discarded elements as garbage.
+    let total = region_bytes(&arena);
+    arena.truncate(5000);
+    let garbage = arena.garbage_bytes();
+    assert!(garbage > 0);
+    arena.truncate(100);
+    assert!(arena.garbage_bytes() > garbage);
+    assert_eq!(region_bytes(&arena), total);
+    for (index, element) in arena.iter().enumerate() {
+        assert_eq!(element, &(index as u64, index.to_string(), vec![index.to_string(); 2]));
+    }
+
+    // Pops count garbage too, which compaction reclaims.
+    let garbage = arena.garbage_bytes();
+    assert!(arena.pop());
+    assert_eq!(arena.len(), 99);
+    assert!(arena.garbage_bytes() > garbage);
+    arena.compact();
+    assert_eq!(arena.garbage_bytes(), 0);
+    assert!(region_bytes(&arena) < used);
+    assert_eq!(arena[98], (98, "98".to_string(), vec!["98".to_string(); 2]));
+
+    // Truncation beyond the garbage fraction triggers compaction.
+    arena.set_compact_fraction(Some(0.5));
+    arena.truncate(10);
+    assert_eq!(arena.garbage_bytes(), 0);
+    assert_eq!(arena[9], (9, "9".to_string(), vec!["9".to_string(); 2]));
+    arena.truncate(0);
+    assert_eq!(region_bytes(&arena), 0);
+    assert!(!arena.pop());
+}
+
+#[test]
+fn test_gather() {
+    let arena: ColumnStack<(u64, String)> = (0 .. 200u64).map(|index| (index, index.to_string())).collect::<Vec<_>>().iter().collect();
+
+    let indices = [7, 3, 3, 150, 0];
+    let gathered = arena.gather(&indices);
+    assert_eq!(gathered.len(), indices.len());
+    for (element, index) in gathered.iter().zip(indices) {
+        assert_eq!(element, &arena[index]);
+    }
+    let (used, allocated) = gathered.summed_heap_size();
+    assert_eq!(used, allocated);
+
+    let mask = [0b1010, 0, 1 << 63, u64::MAX];
+    let selected = arena.select_by_mask(&mask);
+    let expected = [1, 3, 191, 192, 193, 194, 195, 196, 197, 198, 199];
+    assert_eq!(selected, arena.gather(&expected));
+    assert!(arena.select_by_mask(&[]).is_empty());
+}
+
+#[test]
+fn test_partition() {
+    let arena: ColumnStack<(u64, String)> = (0 .. 1000u64).map(|index| (index, index.to_string())).collect::<Vec<_>>().iter().collect();
+
+    let shards = arena.partition_into(7, |x| x.0);
+    assert_eq!(shards.len(), 7);
+    assert_eq!(shards.iter().map(|shard| shard.len()).sum::<usize>(), arena.len());
+    for (index, shard) in shards.iter().enumerate() {
+        let expected: Vec<_> = arena.iter().filter(|x| x.0 % 7 == index as u64).cloned().collect();
+        assert_eq!(&shard[..], &expected[..]);
+        let (used, allocated) = shard.summed_heap_size();
+        assert_eq!(used, allocated);
+    }
+
+    let mut outputs = shards;
+    arena.partition_extend(&mut outputs[.. 2], |x| x.1.len() as u64);
+    assert_eq!(outputs[0].len(), 143 + 90);
+    assert_eq!(outputs[1].len(), 143 + 910);
+}
+
+#[test]
+fn test_column_stack_builder() {
+    let mut builder = ColumnStackBuilder::<(u64, String)>::with_targets(4096, 100);
+    let mut batches = Vec::new();
+    for index in 0 .. 10000u64 {
+        builder.copy(&(index, format!("{}{}", index, "grawwwwrr!".repeat(index as usize % 5))));
+        while let Some(batch) = builder.extract() {
+            let (used, _) = batch.summed_heap_size();
+            assert!(batch.len() == 100 || used >= 4096);
+            // Batches exceed the target by less than one record, and the spines of their region's stashes.
+            let stashes = batch.stats().stashed_chunks * std::mem::size_of::<Vec<u8>>();
+            assert!(used < 4096 + 128 + stashes);
+            batches.push(batch);
+        }
+    }
+    assert!(!builder.is_empty());
+    while let Some(batch) = builder.finish() {
+        batches.push(batch);
+    }
+    assert!(builder.is_empty());
+    assert_eq!(batches.iter().map(|batch| batch.len()).sum::<usize>(), 10000);
+    assert!(batches.iter().flat_map(|batch| batch.iter()).map(|x| x.0).eq(0 .. 10000));
+
+    // Recycled stacks are reused.
+    for batch in batches.drain(..) {
+        builder.recycle(batch);
+    }
+    builder.copy(&(0, "grawwwwrr!".to_string()));
+    let batch = builder.finish().unwrap();
+    let fresh: ColumnStack<_> = std::iter::once(&(0, "grawwwwrr!".to_string())).collect();
+    assert_eq!(batch, fresh);
+    assert!(batch.summed_heap_size().1 > fresh.summed_heap_size().1);
+}
+
+#[test]
+fn test_chunks_by_bytes() {
+    let arena: ColumnStack<(u64, String)> = (0 .. 1000u64)
+        .map(|index| (index, "grawwwwrr!".repeat(index as usize % 7)))
+        .collect::<Vec<_>>()
+        .iter()
+        .collect();
+
+    let ranges: Vec<_> = arena.chunks_by_bytes(2048).collect();
+    assert_eq!(ranges.first().unwrap().start, 0);
+    assert_eq!(ranges.last().unwrap().end, arena.len());
+    assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start && pair[0].start < pair[0].end));
+
+    let stacks = arena.split_into_stacks(2048);
+    assert_eq!(stacks.len(), ranges.len());
+    for (stack, range) in stacks.iter().zip(ranges) {
+        assert_eq!(&stack[..], &arena[range]);
+        let (used, allocated) = stack.summed_heap_size();
+        assert_eq!(used, allocated);
+        assert!(used <= 2048);
+    }
+
+    // Elements larger than the limit are split on their own.
+    assert_eq!(arena.chunks_by_bytes(0).count(), arena.len());
+    assert_eq!(ColumnStack::<String>::default().chunks_by_bytes(0).count(), 0);
+}
+
+fn _item_heap_size_pass<T: Columnation + std::fmt::Debug>(record: T)
+where
+    T::InnerRegion: RegionCapacity,
+{
+    // Reserving for the item, either directly or by a capacity plan, should suffice to copy it.
+    for plan in [false, true] {
+        let mut region = T::InnerRegion::default();
+        if plan {
+            region.reserve_capacity(&T::InnerRegion::measure_items(std::iter::once(&record)));
+        } else {
+            region.reserve_items(std::iter::once(&record));
+        }
+        let mut reserved = 0;
+        region.heap_size(|_, cap| reserved += cap);
+        std::mem::forget(unsafe { region.copy(&record) });
+        let (mut used, mut allocated) = (0, 0);
+        region.heap_size(|len, cap| { used += len; allocated += cap; });
+        assert_eq!(format!("{:?}", region.measure()), format!("{:?}", T::InnerRegion::measure_item(&record)));
+        region.clear();
+        assert_eq!(used, T::InnerRegion::item_heap_size(&record), "{:?}", record);
+        assert_eq!(allocated, reserved, "{:?}", record);
+    }
+}
+
+#[test]
+fn test_item_heap_size() {
+    _item_heap_size_pass(0u64);
+    _item_heap_size_pass("grawwwwrr!".to_string());
+    _item_heap_size_pass(vec!["grawwwwrr!".to_string(); 3]);
+    _item_heap_size_pass((1u8, vec![Some(1u64), None], Box::new("grawwwwrr!".to_string())));
+    _item_heap_size_pass(Ok::<_, ()>(["a".to_string(), "bc".to_string()]));
+    _item_heap_size_pass(std::ffi::CString::new("grawwwwrr!").unwrap());
+    _item_heap_size_pass(std::collections::VecDeque::from(vec![vec![0u8; 5]; 2]));
+    _item_heap_size_pass(std::ops::Range { start: "a".to_string(), end: "b".to_string() });
+    _item_heap_size_pass(smallvec::SmallVec::<[String; 2]>::from_vec(vec!["a".to_string(); 3]));
+    _item_heap_size_pass(smallvec::SmallVec::<[String; 2]>::from_vec(vec!["a".to_string()]));
+}
+
+#[test]
+fn test_capacity() {
+    // Plans can be stored, scaled, and applied to later stacks.
+    let records: Vec<_> = (0 .. 100u64).map(|index| (index, index.to_string())).collect();
+    let batch: ColumnStack<_> = records.iter().collect();
+    let mut plan = batch.measure();
+    plan.add(&batch.measure());
+    plan.scale(0.5);
+    let mut stack = ColumnStack::default();
+    stack.reserve_capacity(&plan);
+    let (_, reserved) = stack.summed_heap_size();
+    stack.extend(&records);
+    assert_eq!(stack.summed_heap_size(), (reserved, reserved));
+}
+
+#[test]
+fn test_heap_report() {
+    let records: Vec<_> = (0 .. 1000u64)
+        .map(|index| (index, index.to_string(), vec!["grawwwwrr!".to_string(); index as usize % 3]))
+        .collect();
+    let arena: ColumnStack<_> = records.iter().collect();
+
+    let report = arena.heap_report();
+    let paths: Vec<_> = report.iter().map(|(path, _)| path).collect();
+    assert_eq!(paths, [".1.string.bytes", ".2.vec.elements", ".2.vec.string.bytes", ".spine"]);
+    let total = report.total();
+    assert_eq!((total.used, total.reserved), arena.summed_heap_size());
+    assert_eq!(total.allocations, total.histogram.iter().sum::<usize>());
+
+    // Used bytes include the bookkeeping of the regions, in addition to the strings.
+    let strings = report.get(".2.vec.string.bytes").unwrap();
+    let bytes = records.iter().map(|x| x.2.len()).sum::<usize>() * "grawwwwrr!".len();
+    assert!(strings.used >= bytes && strings.used < bytes + 1024);
+    let bytes = records.iter().map(|x| x.1.len()).sum::<usize>();
+    assert!(report.get(".1.string.bytes").unwrap().used >= bytes);
+    assert!(report.get(".0").is_none());
+    assert!(report.to_string().contains(".2.vec.elements: "));
+}
+
+#[cfg(feature = "hooks")]
+#[test]
+fn test_region_hook() {
+    use std::sync::{Arc, Mutex};
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let hook = {
+        let events = Arc::clone(&events);
+        RegionHook::new(move |event| events.lock().unwrap().push(event))
+    };
+
+    let mut arena = ColumnStack::<(u64, String, Vec<String>)>::default();
+    arena.set_hook(Some(hook.clone()));
+    for index in 0 .. 1000u64 {
+        arena.copy(&(index, index.to_string(), vec!["grawwwwrr!".to_string(); 2]));
+    }
+    let allocated = |events: &[RegionEvent]| events.iter().map(|event| match event {
+        RegionEvent::Allocate { bytes, .. } => *bytes,
+        _ => 0,
+    }).sum::<usize>();
+    {
+        let events = events.lock().unwrap();
+        assert!(events.iter().any(|event| matches!(event, RegionEvent::Stash { .. })));
+        // Chunks are all of the region's allocations, other than the spines of their stashes.
+        let spine = arena.heap_report().get(".spine").unwrap().reserved;
+        let reserved = arena.summed_heap_size().1 - spine;
+        assert!(allocated(&events) > 0 && allocated(&events) <= reserved);
+    }
+
+    // Compaction reinstalls the hook in the new region, which is observed allocating.
+    events.lock().unwrap().clear();
+    arena.compact();
+    {
+        let events = events.lock().unwrap();
+        assert!(events.iter().any(|event| matches!(event, RegionEvent::Clear { .. })));
+        assert!(events.iter().any(|event| matches!(event, RegionEvent::Drop { .. })));
+        assert!(allocated(&events) > 0);
+    }
+
+    // Rolling back releases each chunk allocated since the checkpoint.
+    {
+        let mut checkpoint = arena.checkpoint();
+        events.lock().unwrap().clear();
+        for index in 0 .. 1000u64 {
+            checkpoint.copy(&(index, index.to_string(), vec!["grawwwwrr!".to_string(); 2]));
+        }
+        checkpoint.rollback();
+    }
+    {
+        let events = events.lock().unwrap();
+        let released = events.iter().map(|event| match event {
+            RegionEvent::Release { bytes } => *bytes,
+            _ => 0,
+        }).sum::<usize>();
+        assert!(released > 0);
+        assert_eq!(released, allocated(&events));
+    }
+
+    // Gathered stacks inherit the hook, and are observed reserving their region.
+    events.lock().unwrap().clear();
+    let gathered = arena.gather(&[0, 10, 100]);
+    assert!(allocated(&events.lock().unwrap()) > 0);
+    drop(gathered);
+
+    // Queues install the hook in each of their segments.
+    events.lock().unwrap().clear();
+    let mut queue = ColumnQueue::<String>::with_segment_len(10);
+    queue.set_hook(Some(hook.clone()));
+    for index in 0 .. 100 {
+        queue.copy(&format!("grawwwwrr! {}", index));
+    }
+    assert!(allocated(&events.lock().unwrap()) >= 100 * "grawwwwrr! ".len());
+    drop(queue);
+
+    // Recycled stacks retain their allocations, so that a builder in steady state allocates nothing.
+    let mut builder = ColumnStackBuilder::<(u64, String)>::with_targets(1 << 16, usize::MAX);
+    builder.set_hook(Some(hook.clone()));
+    for round in 0 .. 2 {
+        if round == 1 {
+            events.lock().unwrap().clear();
+        }
+        for index in 0 .. 1000u64 {
+            builder.copy(&(index, "grawwwwrr!".repeat(index as usize % 5)));
+            while let Some(batch) = builder.extract() {
+                builder.recycle(batch);
+            }
+        }
+        while let Some(batch) = builder.finish() {
+            builder.recycle(batch);
+        }
+    }
+    {
+        let events = events.lock().unwrap();
+        assert!(events.iter().any(|event| matches!(event, RegionEvent::Clear { released: 0, .. })));
+        assert_eq!(allocated(&events), 0);
+    }
+    drop(builder);
+
+    // Without a hook, nothing is observed.
+    arena.set_hook(None);
+    events.lock().unwrap().clear();
+    arena.clear();
+    drop(arena);
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_column_stack_stats() {
+    let mut arena = ColumnStack::<(u64, String)>::default();
+    for index in 0 .. 1000u64 {
+        arena.copy(&(index, "grawwwwrr!".repeat(index as usize % 3)));
+    }
+    let stats = arena.stats();
+    let (used, reserved) = arena.summed_heap_size();
+    assert_eq!(stats.spine_bytes + stats.live_bytes + stats.dead_bytes, used);
+    assert_eq!(used + stats.spine_unused_bytes + stats.unused_bytes, reserved);
+    assert_eq!(stats.dead_bytes, 0);
+    assert!(stats.chunks > stats.stashed_chunks && stats.stashed_chunks > 0);
+
+    // Removed elements are dead, until compaction.
+    arena.set_compact_fraction(None);
+    arena.retain_from(0, |x| x.1.is_empty());
+    let stats = arena.stats();
+    assert_eq!(stats.dead_bytes, (333 + 333 * 2) * "grawwwwrr!".len());
+    assert_eq!(stats.spine_bytes, 334 * std::mem::size_of::<(u64, String)>());
+    arena.compact();
+    let stats = arena.stats();
+    assert_eq!(stats.dead_bytes, 0);
+    assert_eq!(stats.stashed_chunks, 0);
+
+    // Removing elements compacts the stack once garbage exceeds the compaction fraction.
+    for index in 0 .. 1000u64 {
+        arena.copy(&(index, "grawwwwrr!".to_string()));
+    }
+    arena.set_compact_fraction(Some(0.5));
+    arena.retain_from(0, |x| x.1.is_empty());
+    assert_eq!(arena.len(), 334);
+    assert_eq!(arena.stats().dead_bytes, 0);
+}